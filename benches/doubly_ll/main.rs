@@ -0,0 +1,101 @@
+//! Compares the raw pointer `DoublyLinkedList` with the `Rc<RefCell>` based
+//! `RcDoublyLinkedList` it replaced and the index based `VecLinkedList`.
+//! Run with `cargo bench`.
+
+mod rc_doubly_ll;
+
+use std::{hint::black_box, time::{Duration, Instant}};
+
+use dsa_with_rust::linked_list::{DoublyLinkedList, VecLinkedList};
+use rc_doubly_ll::RcDoublyLinkedList;
+
+const LEN: usize = 100_000;
+const ROUNDS: u32 = 20;
+
+/// Average time of one call to `f` over `ROUNDS` calls
+fn time<F: FnMut()>(mut f: F) -> Duration {
+    // One call to warm up the allocator and caches
+    f();
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        f();
+    }
+    start.elapsed() / ROUNDS
+}
+
+/// `LEN` values in a fixed pseudo-random order, from a xorshift generator
+fn shuffled() -> Vec<usize> {
+    let mut state: u64 = 0x9e37_79b9_7f4a_7c15;
+    (0..LEN)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as usize % LEN
+        })
+        .collect()
+}
+
+/// Run every benchmark against one list type, all types share this API
+macro_rules! bench_list {
+    ($list:ident) => {{
+        let full: $list<usize> = (0..LEN).collect();
+        let shuffled = shuffled();
+        [
+            ("push_back", time(|| {
+                let mut list = $list::new();
+                for value in 0..LEN {
+                    list.push_back(value);
+                }
+                black_box(list);
+            })),
+            ("iter sum", time(|| {
+                black_box(full.iter().sum::<usize>());
+            })),
+            ("find last", time(|| {
+                black_box(full.find(black_box(&(LEN - 1))));
+            })),
+            ("get middle", time(|| {
+                black_box(full.get(black_box(LEN / 2)));
+            })),
+            ("pop_front all", time(|| {
+                let mut list = full.clone();
+                while let Some(value) = list.pop_front() {
+                    black_box(value);
+                }
+            })),
+            ("retain evens", time(|| {
+                let mut list = full.clone();
+                list.retain(|value| value % 2 == 0);
+                black_box(list);
+            })),
+            // Sorting in place would only sort sorted input after the first
+            // round, so every round builds the list again
+            ("build + sort", time(|| {
+                let mut list: $list<usize> = shuffled.iter().copied().collect();
+                list.sort_by(|a, b| a.cmp(b));
+                black_box(list);
+            })),
+        ]
+    }};
+}
+
+fn main() {
+    let raw = bench_list!(DoublyLinkedList);
+    let rc = bench_list!(RcDoublyLinkedList);
+    let vec = bench_list!(VecLinkedList);
+
+    println!("{} nodes, average of {} rounds, ratios are time / NonNull time", LEN, ROUNDS);
+    println!(
+        "{:<16}{:>14}{:>14}{:>14}{:>8}{:>8}",
+        "", "NonNull", "Rc<RefCell>", "Vec arena", "Rc", "Vec"
+    );
+    for (((name, raw), (_, rc)), (_, vec)) in raw.iter().zip(rc.iter()).zip(vec.iter()) {
+        println!(
+            "{:<16}{:>14?}{:>14?}{:>14?}{:>7.1}x{:>7.1}x",
+            name, raw, rc, vec,
+            rc.as_secs_f64() / raw.as_secs_f64(),
+            vec.as_secs_f64() / raw.as_secs_f64()
+        );
+    }
+}
@@ -0,0 +1,424 @@
+//! The `Rc<RefCell<Node>>` based doubly linked list that `DoublyLinkedList`
+//! used before it moved to raw pointers, cut down to the operations the
+//! benchmark compares. Not part of the library API.
+
+use std::{cell::{RefCell, RefMut}, cmp::Ordering, rc::Rc};
+
+#[derive(Debug, Clone)]
+struct Node<T> {
+    data: T,
+    next: Option<Rc<RefCell<Node<T>>>>,
+    prev: Option<Rc<RefCell<Node<T>>>>,
+}
+
+pub struct RcDoublyLinkedList<T> {
+    head: Option<Rc<RefCell<Node<T>>>>,
+    tail: Option<Rc<RefCell<Node<T>>>>,
+    len: usize,
+}
+
+impl<T> Default for RcDoublyLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Every pair of neighbours holds a strong `Rc` to each other, so the default
+// drop would leak the whole list. Unlink the nodes first.
+impl<T> Drop for RcDoublyLinkedList<T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T> RcDoublyLinkedList<T> {
+    /// Create a new doubly linked list
+    /// 
+    /// Will create a new doubly linked list with no nodes
+    pub fn new() -> Self {
+        RcDoublyLinkedList { head: None, tail: None, len: 0 }
+    }
+
+    /// Push a new node to the back of the list
+    /// 
+    /// Will create a new node with the given data and add it to the end of the list,
+    /// an empty list gets the node as both head and tail
+    /// 
+    /// args:
+    /// * `data`: The data to be added to the list
+    pub fn push_back(&mut self, data: T) {
+        let new_node = Rc::new(RefCell::new(Node {
+            data,
+            next: None,
+            prev: None
+        }));
+
+        match self.tail.take() {
+            Some(old_tail) => {
+                new_node.borrow_mut().prev = Some(old_tail.clone());
+                old_tail.borrow_mut().next = Some(new_node.clone());
+                self.tail = Some(new_node);
+            }
+            None => {
+                // List is empty
+                self.head = Some(new_node.clone());
+                self.tail = Some(new_node);
+            }
+        }
+        self.len += 1;
+    }
+
+    /// Remove the front node of the list
+    /// 
+    /// return:
+    /// * `Option<T>` -> The data of the removed node, `None` if the list is empty
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.head.take().and_then(|old_head| {
+            match old_head.borrow_mut().next.take() {
+                Some(new_head) => {
+                    new_head.borrow_mut().prev = None;
+                    self.head = Some(new_head);
+                }
+                None => {
+                    // Removed the only node
+                    self.tail = None;
+                }
+            }
+
+            self.len -= 1;
+            Self::into_data(old_head)
+        })
+    }
+
+    /// Finding a node/data in a list and return the index of the node from the list
+    /// 
+    /// return: 
+    /// * `(bool, usize)` -> `(true, index)` | `(false, 0)`
+    pub fn find(&self, data: &T) -> (bool, usize)
+    where
+        T: PartialEq
+    {
+        match self.iter().position(|value| value == data) {
+            Some(index) => (true, index),
+            None => (false, 0),
+        }
+    }
+
+    /// Get a reference to the data at a specific index
+    /// 
+    /// Walks from the head or the tail, whichever is closer to `index`
+    /// 
+    /// return:
+    /// * `Option<&T>` -> `None` if `index` is out of range
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+
+        if index < self.len / 2 {
+            self.iter().nth(index)
+        } else {
+            self.iter().rev().nth(self.len - 1 - index)
+        }
+    }
+
+    /// Keep only the nodes whose data matches a predicate, in a single pass
+    /// 
+    /// args:
+    /// * `keep`: Returns false for the nodes to remove
+    pub fn retain<F>(&mut self, mut keep: F)
+    where
+        F: FnMut(&T) -> bool
+    {
+        let mut cursor = self.cursor_front_mut();
+        loop {
+            let keep_current = match cursor.current() {
+                Some(data) => keep(&data),
+                None => break,
+            };
+
+            if keep_current {
+                cursor.move_next();
+            } else {
+                cursor.remove_current();
+            }
+        }
+    }
+
+    /// Check if the list is empty
+    /// 
+    /// return:
+    /// * `bool` -> `true` if the list has no nodes
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    /// Remove every node from the list
+    /// 
+    /// Nodes are unlinked one at a time from the front, so the `prev`/`next`
+    /// cycle between neighbours is broken and every node is freed
+    pub fn clear(&mut self) {
+        while !self.is_empty() {
+            self.pop_front();
+        }
+    }
+
+    /// Unlink `node` from its neighbours, moving head/tail if it was at either end
+    fn unlink_node(&mut self, node: &Rc<RefCell<Node<T>>>) {
+        let (prev, next) = {
+            let mut node = node.borrow_mut();
+            (node.prev.take(), node.next.take())
+        };
+
+        match &prev {
+            Some(prev_node) => prev_node.borrow_mut().next = next.clone(),
+            None => self.head = next.clone(),
+        }
+        match next {
+            Some(next_node) => next_node.borrow_mut().prev = prev,
+            None => self.tail = prev,
+        }
+        self.len -= 1;
+    }
+
+    /// Take the data out of a node that has been unlinked from the list
+    /// 
+    /// return:
+    /// * `Option<T>` -> `None` if the node is still referenced by another node
+    fn into_data(node: Rc<RefCell<Node<T>>>) -> Option<T> {
+        Rc::try_unwrap(node).ok().map(|node| node.into_inner().data)
+    }
+
+    /// Sort the list with a comparator function, keeping equal elements in order
+    /// 
+    /// The sort only follows the `next` links, the `prev` links and the tail
+    /// are rebuilt in one pass afterwards.
+    /// 
+    /// args:
+    /// * `cmp`: returns how the first element is ordered relative to the second
+    pub fn sort_by<F>(&mut self, mut cmp: F)
+    where
+        F: FnMut(&T, &T) -> Ordering
+    {
+        if self.len < 2 {
+            return;
+        }
+
+        self.tail = None;
+        self.head = Self::merge_sort(self.head.take(), self.len, &mut cmp);
+
+        let mut prev: Option<Rc<RefCell<Node<T>>>> = None;
+        let mut current = self.head.clone();
+        while let Some(node) = current {
+            node.borrow_mut().prev = prev;
+            current = node.borrow().next.clone();
+            prev = Some(node);
+        }
+        self.tail = prev;
+    }
+
+    /// Sort a chain of `len` nodes by their `next` links, leaving `prev` stale
+    fn merge_sort<F>(head: Option<Rc<RefCell<Node<T>>>>, len: usize, cmp: &mut F) -> Option<Rc<RefCell<Node<T>>>>
+    where
+        F: FnMut(&T, &T) -> Ordering
+    {
+        if len < 2 {
+            return head;
+        }
+
+        // Cut the chain after its first half
+        let mid = len / 2;
+        let mut last = head.clone()?;
+        for _ in 1..mid {
+            let next = last.borrow().next.clone()?;
+            last = next;
+        }
+        let right = last.borrow_mut().next.take();
+
+        let left = Self::merge_sort(head, mid, cmp);
+        let right = Self::merge_sort(right, len - mid, cmp);
+        Self::merge(left, right, cmp)
+    }
+
+    /// Merge two sorted chains into one by their `next` links, taking from `left` first on ties
+    fn merge<F>(mut left: Option<Rc<RefCell<Node<T>>>>, mut right: Option<Rc<RefCell<Node<T>>>>, cmp: &mut F) -> Option<Rc<RefCell<Node<T>>>>
+    where
+        F: FnMut(&T, &T) -> Ordering
+    {
+        let mut head: Option<Rc<RefCell<Node<T>>>> = None;
+        let mut tail: Option<Rc<RefCell<Node<T>>>> = None;
+
+        while let (Some(l), Some(r)) = (&left, &right) {
+            let take_left = cmp(&l.borrow().data, &r.borrow().data) != Ordering::Greater;
+            let source = if take_left { &mut left } else { &mut right };
+            let node = source.take().unwrap();
+            *source = node.borrow_mut().next.take();
+
+            match &tail {
+                Some(tail_node) => tail_node.borrow_mut().next = Some(node.clone()),
+                None => head = Some(node.clone()),
+            }
+            tail = Some(node);
+        }
+
+        // One side ran out, the rest of the other one is already in order
+        let rest = left.or(right);
+        match &tail {
+            Some(tail_node) => tail_node.borrow_mut().next = rest,
+            None => head = rest,
+        }
+        head
+    }
+
+    /// Get a cursor pointing at the head node that can edit the list in place
+    /// 
+    /// An empty list gives a cursor at the "ghost" position, see [`CursorMut`]
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut {
+            current: self.head.clone(),
+            index: 0,
+            list: self
+        }
+    }
+
+    /// Iterate over references to the data, from head to tail
+    /// 
+    /// Also iterates from tail to head through `.rev()`
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            head: self.head.as_deref(),
+            tail: self.tail.as_deref(),
+            len: self.len
+        }
+    }
+}
+
+/// Cursor returned by [`RcDoublyLinkedList::cursor_front_mut`]
+pub struct CursorMut<'a, T> {
+    list: &'a mut RcDoublyLinkedList<T>,
+    current: Option<Rc<RefCell<Node<T>>>>,
+    index: usize,
+}
+
+impl<T> CursorMut<'_, T> {
+    /// Get the data under the cursor, `None` at the ghost position
+    pub fn current(&mut self) -> Option<RefMut<'_, T>> {
+        self.current
+            .as_ref()
+            .map(|node| RefMut::map(node.borrow_mut(), |node| &mut node.data))
+    }
+
+    /// Move to the next node
+    /// 
+    /// From the tail this moves to the ghost position, and from the ghost
+    /// position to the head
+    pub fn move_next(&mut self) {
+        match self.current.take() {
+            Some(node) => {
+                self.current = node.borrow().next.clone();
+                self.index += 1;
+            }
+            None => {
+                self.current = self.list.head.clone();
+                self.index = 0;
+            }
+        }
+    }
+
+    /// Remove the node under the cursor and move to the next one
+    /// 
+    /// return:
+    /// * `Option<T>` -> The removed data, `None` at the ghost position
+    pub fn remove_current(&mut self) -> Option<T> {
+        let node = self.current.take()?;
+        self.current = node.borrow().next.clone();
+        self.list.unlink_node(&node);
+
+        RcDoublyLinkedList::into_data(node)
+    }
+}
+
+// The iterators below hand out references into `RefCell`s without holding a
+// `Ref`/`RefMut` guard. This is sound because they borrow the list itself:
+// `iter` holds `&self` so nothing can mutate the nodes while it lives, and
+// `iter_mut` holds `&mut self` and yields each node's data exactly once.
+
+/// Borrowing iterator returned by [`RcDoublyLinkedList::iter`]
+pub struct Iter<'a, T> {
+    head: Option<&'a RefCell<Node<T>>>,
+    tail: Option<&'a RefCell<Node<T>>>,
+    len: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.head.map(|cell| {
+            // SAFETY: see the comment above `Iter`
+            let node = unsafe { &*cell.as_ptr() };
+            self.head = node.next.as_deref();
+            self.len -= 1;
+            &node.data
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.tail.map(|cell| {
+            // SAFETY: see the comment above `Iter`
+            let node = unsafe { &*cell.as_ptr() };
+            self.tail = node.prev.as_deref();
+            self.len -= 1;
+            &node.data
+        })
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+// Written out because deriving it would require `T: Clone`
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Iter {
+            head: self.head,
+            tail: self.tail,
+            len: self.len
+        }
+    }
+}
+
+impl<T> Extend<T> for RcDoublyLinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for data in iter {
+            self.push_back(data);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for RcDoublyLinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T: Clone> Clone for RcDoublyLinkedList<T> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
@@ -0,0 +1,53 @@
+//! Walks through the linked list algorithms, run with `cargo run --example algorithms`
+
+use dsa_with_rust::linked_list::{
+    algorithms::{
+        brent_cycle, floyd_cycle, has_cycle, is_palindrome, is_palindrome_by_ends, kth_from_end,
+        middle, pairwise_swap, partition, reverse_in_groups, rotate_left, rotate_right,
+    },
+    DoublyLinkedList, SinglyLinkedList,
+};
+
+fn main() {
+    // A list stored as a table of `next` indices, 5 links back to 2
+    let next = [Some(1), Some(2), Some(3), Some(4), Some(5), Some(2)];
+    let follow = |index: usize| next[index];
+    println!("Has cycle: {}", has_cycle(0, follow));
+    println!("Floyd: {:?}", floyd_cycle(0, follow));
+    println!("Brent: {:?}", brent_cycle(0, follow));
+
+    let straight = [Some(1), Some(2), None];
+    println!("Straight list has cycle: {}", has_cycle(0, |index: usize| straight[index]));
+
+    // Iterating x -> x * x + 1 modulo a number always ends up in a cycle
+    println!("Rho: {:?}", brent_cycle(3u64, |x| Some((x * x + 1) % 1009)));
+
+    let singly: SinglyLinkedList<_> = (1..=6).collect();
+    let doubly: DoublyLinkedList<_> = (1..=7).collect();
+    println!("Middle: {:?} / {:?}", middle(&singly), middle(&doubly));
+    println!("2nd from end: {:?} / {:?}", kth_from_end(&singly, 2), kth_from_end(&doubly, 2));
+
+    let mut word: SinglyLinkedList<_> = "racecar".chars().collect();
+    let word_is_palindrome = is_palindrome(&mut word);
+    println!("{} is a palindrome: {}", word, word_is_palindrome);
+    let numbers: DoublyLinkedList<_> = [1, 2, 3, 2, 1].into_iter().collect();
+    println!("{} is a palindrome: {}", numbers, is_palindrome_by_ends(&numbers));
+
+    let mut groups: SinglyLinkedList<_> = (1..=8).collect();
+    reverse_in_groups(&mut groups, 3);
+    println!("Reversed in groups of 3: {}", groups);
+
+    let mut pairs: DoublyLinkedList<_> = (1..=5).collect();
+    pairwise_swap(&mut pairs);
+    println!("Pairs swapped: {}", pairs);
+
+    let mut rotated: SinglyLinkedList<_> = (1..=5).collect();
+    rotate_left(&mut rotated, 2);
+    println!("Rotated left by 2: {}", rotated);
+    rotate_right(&mut rotated, 7);
+    println!("Rotated right by 7: {}", rotated);
+
+    let mut split: DoublyLinkedList<_> = [3, 5, 8, 5, 10, 2, 1].into_iter().collect();
+    partition(&mut split, &5);
+    println!("Partitioned around 5: {}", split);
+}
@@ -0,0 +1,39 @@
+//! Walks through the `CircularDoublyLinkedList` API, run with `cargo run --example circular_doubly_ll`
+
+use dsa_with_rust::linked_list::CircularDoublyLinkedList;
+
+fn main() {
+    let mut circular_dll = CircularDoublyLinkedList::new();
+
+    circular_dll.push_back(10);
+    circular_dll.push_back(20);
+    circular_dll.push_back(30);
+    circular_dll.push_front(0);
+    circular_dll.display();
+    circular_dll.check_links();
+
+    circular_dll.rotate_left(1);
+    circular_dll.display();
+
+    circular_dll.rotate_right(2);
+    circular_dll.display();
+
+    if let Some(value) = circular_dll.pop_front() {
+        println!("Popped front: {}", value);
+    }
+    if let Some(value) = circular_dll.pop_back() {
+        println!("Popped back: {}", value);
+    }
+    circular_dll.display();
+
+    let other: CircularDoublyLinkedList<_> = (100..103).collect();
+    if let Err(err) = circular_dll.splice(1, other) {
+        println!("{}", err);
+    }
+    circular_dll.display();
+    circular_dll.check_links();
+
+    let backwards: Vec<_> = circular_dll.iter().rev().collect();
+    println!("Backwards: {:?}", backwards);
+    println!("List Length: {}", circular_dll.len());
+}
@@ -0,0 +1,98 @@
+//! Walks through the `CircularLinkedList` API, run with `cargo run --example circular_ll`
+
+use dsa_with_rust::linked_list::CircularLinkedList;
+
+fn main() {
+    let mut circular_ll = CircularLinkedList::new();
+
+    circular_ll.push_front(50);
+    circular_ll.push_front(60);
+
+    circular_ll.display();
+
+    circular_ll.push_back(70);
+    circular_ll.push_back(80);
+    circular_ll.display();
+
+    if let Some(value) = circular_ll.pop_front() {
+        println!("Popped front: {}", value);
+    }
+    if let Some(value) = circular_ll.pop_back() {
+        println!("Popped back: {}", value);
+    }
+    circular_ll.display();
+
+    if let Err(err) = circular_ll.insert_at_index(1, 100) {
+        println!("{}", err);
+    }
+    if let Err(err) = circular_ll.insert_after(&70, 200) {
+        println!("{}", err);
+    }
+    circular_ll.display();
+
+    let (found, index) = circular_ll.find(&100);
+    if found {
+        println!("Found at index: {}", index);
+    }
+
+    if let Err(err) = circular_ll.delete_at_node(&200) {
+        println!("{}", err);
+    }
+    circular_ll.reverse();
+    circular_ll.display();
+    println!("List Length: {}", circular_ll.len());
+
+    circular_ll.rotate_left(1);
+    circular_ll.display();
+    circular_ll.rotate_right(1);
+    circular_ll.display();
+
+    let round_robin: Vec<_> = circular_ll.cursor().take(7).collect();
+    println!("Round robin: {:?}", round_robin);
+    let two_cycles: Vec<_> = circular_ll.take_cycles(2).collect();
+    println!("Two cycles: {:?}", two_cycles);
+
+    // Josephus problem: every 3rd of 7 people is eliminated
+    let mut circle: CircularLinkedList<_> = (1..=7).collect();
+    let mut order = Vec::new();
+    while !circle.is_empty() {
+        circle.rotate_left(2);
+        order.extend(circle.pop_front());
+    }
+    println!("Elimination order: {:?}", order);
+
+    for value in circular_ll.iter_mut() {
+        *value += 5;
+    }
+    let values: Vec<_> = circular_ll.into_iter().collect();
+    println!("Values: {:?}", values);
+
+    let mut indexed: CircularLinkedList<_> = (0..5).collect();
+    indexed[1] = 10;
+    if let Ok(old) = indexed.set(2, 20) {
+        println!("Replaced: {}", old);
+    }
+    if let Ok(removed) = indexed.remove_at(3) {
+        println!("Removed at 3: {}", removed);
+    }
+    if let Some(value) = indexed.get(3) {
+        println!("Now at 3: {}", value);
+    }
+    println!("Indexed: {}", indexed);
+
+    let collected: CircularLinkedList<_> = (1..=3).collect();
+    println!("Collected: {} {:?}", collected, collected);
+    println!("Clone is equal: {}", collected.clone() == collected);
+
+
+    let mut joined: CircularLinkedList<_> = (1..=3).collect();
+    let mut other: CircularLinkedList<_> = (7..=9).collect();
+    joined.append(&mut other);
+    joined.prepend((-1..=0).collect());
+    if joined.splice_at(5, (4..=6).collect()).is_ok() {
+        println!("Joined: {}", joined);
+    }
+    if let Ok(second_half) = joined.split_off(joined.len() / 2) {
+        println!("First half: {} / second half: {}", joined, second_half);
+    }
+}
@@ -0,0 +1,165 @@
+//! Walks through the `DoublyLinkedList` API, run with `cargo run --example doubly_ll`
+
+use dsa_with_rust::linked_list::DoublyLinkedList;
+
+fn main() {
+    let mut doubly_ll = DoublyLinkedList::new();
+
+    doubly_ll.display();
+    doubly_ll.push_front(10);
+    doubly_ll.push_front(20);
+    doubly_ll.push_front(30);
+    doubly_ll.push_front(40);
+
+    doubly_ll.display();
+    doubly_ll.check_links();
+    
+    doubly_ll.push_back(50);
+    doubly_ll.push_back(60);
+    doubly_ll.display();
+    if let Some(tail) = doubly_ll.get_tail_value() {
+        println!("Tail value: {:?}", *tail);
+    }
+    
+    doubly_ll.push_back(70);
+    doubly_ll.push_back(80);
+    doubly_ll.display();
+    doubly_ll.check_links();
+    if let Some(tail) = doubly_ll.get_tail_value() {
+        println!("Tail value: {:?}", *tail);
+    }
+    
+    if let Err(err) = doubly_ll.push_after_node(&50, 969) {
+        println!("{}", err);
+    }
+    doubly_ll.display();
+    
+
+    if let Err(err) = doubly_ll.push_after_node(&969, 1000) {
+        println!("{}", err);
+    }
+    doubly_ll.display();
+    doubly_ll.check_links();
+
+    if let Ok(inserted) = doubly_ll.push_after_all_nodes(&1000, 5) {
+        println!("Inserted {} nodes", inserted);
+    }
+    doubly_ll.display();
+
+    if let Err(err) = doubly_ll.insert_at_index(doubly_ll.len(), 90) {
+        println!("{}", err);
+    }
+    doubly_ll.display();
+    doubly_ll.check_links();
+    
+
+    println!("List Length: {}", doubly_ll.len());
+    
+    doubly_ll.pop_front();
+    doubly_ll.display();
+    
+    doubly_ll.pop_front();
+    doubly_ll.display();
+    
+    println!("---Delete Back---");
+
+    doubly_ll.pop_back();
+    doubly_ll.display();
+    
+
+    println!("---Delete at node---");
+
+    if let Err(err) = doubly_ll.delete_at_node(&60) {
+        println!("{}", err);
+    }
+    doubly_ll.display();
+    
+    if let Err(err) = doubly_ll.delete_at_node(&10) {
+        println!("{}", err);
+    }
+    doubly_ll.display();
+
+    doubly_ll.reverse();
+    doubly_ll.display();
+    doubly_ll.check_links();
+
+    for value in &mut doubly_ll {
+        *value += 1;
+    }
+    let backwards: Vec<_> = doubly_ll.iter().rev().collect();
+    println!("Backwards: {:?}", backwards);
+
+    let mut filtered: DoublyLinkedList<_> = (1..=10).collect();
+    if let Some(index) = filtered.position(|value| *value > 4) {
+        println!("First above 4 at index: {}", index);
+    }
+    println!("Contains 7: {}", filtered.contains(&7));
+    filtered.retain(|value| value % 3 != 0);
+    filtered.remove_first(&1);
+    println!("Filtered: {}", filtered);
+
+    let mut indexed: DoublyLinkedList<_> = (0..5).collect();
+    indexed[1] = 10;
+    if let Ok(old) = indexed.set(2, 20) {
+        println!("Replaced: {}", old);
+    }
+    if let Ok(removed) = indexed.remove_at(3) {
+        println!("Removed at 3: {}", removed);
+    }
+    if let Some(value) = indexed.get(3) {
+        println!("Now at 3: {}", value);
+    }
+    println!("Indexed: {}", indexed);
+
+    let collected: DoublyLinkedList<_> = (1..=3).collect();
+    println!("Collected: {} {:?}", collected, collected);
+    println!("Clone is equal: {}", collected.clone() == collected);
+
+
+    // Replace every odd number with two copies of its half in a single pass
+    let mut edited: DoublyLinkedList<_> = (1..=6).collect();
+    let mut cursor = edited.cursor_front_mut();
+    while let Some(value) = cursor.current().map(|value| *value) {
+        if value % 2 == 1 {
+            cursor.remove_current();
+            cursor.insert_before(value / 2);
+            cursor.insert_before(value / 2);
+        } else {
+            cursor.move_next();
+        }
+    }
+    println!("Edited: {}", edited);
+
+    let mut cursor = edited.cursor_back_mut();
+    cursor.move_prev();
+    cursor.move_prev();
+    let tail = cursor.split_after();
+    println!("Kept: {} / split off: {}", edited, tail);
+    edited.check_links();
+    tail.check_links();
+
+    let mut joined: DoublyLinkedList<_> = (1..=3).collect();
+    let mut other: DoublyLinkedList<_> = (7..=9).collect();
+    joined.append(&mut other);
+    joined.prepend((-1..=0).collect());
+    if joined.splice_at(5, (4..=6).collect()).is_ok() {
+        println!("Joined: {}", joined);
+    }
+    if let Ok(second_half) = joined.split_off(joined.len() / 2) {
+        println!("First half: {} / second half: {}", joined, second_half);
+        joined.check_links();
+        second_half.check_links();
+    }
+
+    let mut sorted: DoublyLinkedList<_> = [5, 3, 8, 1, 9, 2].into_iter().collect();
+    sorted.sort();
+    sorted.insert_sorted(4);
+    println!("Sorted: {} (is sorted: {})", sorted, sorted.is_sorted());
+
+    // Equal lengths keep their original order because the sort is stable
+    let mut words: DoublyLinkedList<_> = ["pear", "fig", "apple", "kiwi", "plum"].into_iter().collect();
+    words.sort_by_key(|word| word.len());
+    println!("By length: {}", words);
+    words.sort_by(|a, b| b.cmp(a));
+    println!("Descending: {}", words);
+}
@@ -0,0 +1,149 @@
+//! Walks through the `SinglyLinkedList` API, run with `cargo run --example singly_ll`
+
+use dsa_with_rust::linked_list::SinglyLinkedList;
+
+fn main() {
+    let mut singly_ll = SinglyLinkedList::new();
+    singly_ll.push_front(10);
+    singly_ll.push_front(20);
+    singly_ll.push_front(30);
+
+    singly_ll.display();
+
+    singly_ll.push_back(50);
+    singly_ll.push_back(80);
+
+    singly_ll.display(); 
+
+    singly_ll.pop_front();
+    singly_ll.display();
+
+    if let Some(last_value) = singly_ll.pop_back() {
+        println!("Popped Value: {}", last_value);
+    }
+    singly_ll.display();
+
+    if let Err(err) = singly_ll.insert_at_index(2, 100) {
+        println!("{}", err);
+    }
+    singly_ll.display();
+
+    if let Err(err) = singly_ll.insert_after(&20, 100) {
+        println!("{}", err);
+    }
+    singly_ll.display();
+
+    if let Err(err) = singly_ll.insert_after(&20, 500) {
+        println!("{}", err);
+    }
+    singly_ll.display();
+
+    println!("List Length: {}", singly_ll.len());
+
+    let (found, index) = singly_ll.find(&50);
+    if found {
+        println!("Found at index: {}", index);
+    } else {
+        println!("Not found");
+    }
+    
+    if singly_ll.is_empty() {
+        println!("Linked List is empty");
+    } else {
+        println!("Linked List is not empty");
+    }
+
+    singly_ll.reverse();
+    singly_ll.display();
+
+    for value in singly_ll.iter_mut() {
+        *value *= 2;
+    }
+    let doubled: Vec<_> = singly_ll.into_iter().collect();
+    println!("Doubled: {:?}", doubled);
+
+    let mut filtered: SinglyLinkedList<_> = (1..=10).collect();
+    if let Some(index) = filtered.position(|value| *value > 4) {
+        println!("First above 4 at index: {}", index);
+    }
+    println!("Contains 7: {}", filtered.contains(&7));
+    filtered.retain(|value| value % 3 != 0);
+    filtered.remove_first(&1);
+    println!("Filtered: {}", filtered);
+
+    let mut indexed: SinglyLinkedList<_> = (0..5).collect();
+    indexed[1] = 10;
+    if let Ok(old) = indexed.set(2, 20) {
+        println!("Replaced: {}", old);
+    }
+    if let Ok(removed) = indexed.remove_at(3) {
+        println!("Removed at 3: {}", removed);
+    }
+    if let Some(value) = indexed.get(3) {
+        println!("Now at 3: {}", value);
+    }
+    println!("Indexed: {}", indexed);
+
+    let collected: SinglyLinkedList<_> = (1..=3).collect();
+    println!("Collected: {} {:?}", collected, collected);
+    println!("Clone is equal: {}", collected.clone() == collected);
+
+    let long_list: SinglyLinkedList<_> = (0..1_000_000).collect();
+    println!("Built a list of {} nodes", long_list.len());
+    drop(long_list);
+    println!("Dropped it without overflowing the stack");
+
+    // Double every even number and drop every multiple of 3 in a single pass
+    let mut edited: SinglyLinkedList<_> = (1..=10).collect();
+    let mut cursor = edited.cursor_front_mut();
+    while let Some(value) = cursor.current() {
+        if *value % 3 == 0 {
+            cursor.remove_current();
+            continue;
+        }
+        if *value % 2 == 0 {
+            let doubled = *value * 2;
+            cursor.insert_after(doubled);
+            cursor.move_next();
+        }
+        cursor.move_next();
+    }
+    println!("Edited: {}", edited);
+
+    let mut joined: SinglyLinkedList<_> = (1..=3).collect();
+    let mut other: SinglyLinkedList<_> = (7..=9).collect();
+    joined.append(&mut other);
+    joined.prepend((-1..=0).collect());
+    if joined.splice_at(5, (4..=6).collect()).is_ok() {
+        println!("Joined: {}", joined);
+    }
+    if let Ok(second_half) = joined.split_off(joined.len() / 2) {
+        println!("First half: {} / second half: {}", joined, second_half);
+    }
+
+    let mut sorted: SinglyLinkedList<_> = [5, 3, 8, 1, 9, 2].into_iter().collect();
+    sorted.sort();
+    sorted.insert_sorted(4);
+    println!("Sorted: {} (is sorted: {})", sorted, sorted.is_sorted());
+
+    // Equal lengths keep their original order because the sort is stable
+    let mut words: SinglyLinkedList<_> = ["pear", "fig", "apple", "kiwi", "plum"].into_iter().collect();
+    words.sort_by_key(|word| word.len());
+    println!("By length: {}", words);
+    words.sort_by(|a, b| b.cmp(a));
+    println!("Descending: {}", words);
+
+    let evens: SinglyLinkedList<_> = (0..10).step_by(2).collect();
+    let threes: SinglyLinkedList<_> = (0..10).step_by(3).collect();
+    println!("Evens: {} / threes: {}", evens, threes);
+    println!("Union: {}", evens.clone().union(threes.clone()));
+    println!("Intersection: {}", evens.clone().intersection(threes.clone()));
+    println!("Difference: {}", evens.clone().difference(threes.clone()));
+
+    let mut merged = SinglyLinkedList::merge_sorted(evens, threes);
+    println!("Merged: {}", merged);
+    merged.dedup();
+    println!("Deduped: {}", merged);
+    merged.dedup_by_key(|value| *value / 4);
+    println!("One per group of 4: {}", merged);
+}
@@ -0,0 +1,53 @@
+//! Walks through the `VecLinkedList` API, run with `cargo run --example vec_ll`
+
+use dsa_with_rust::linked_list::VecLinkedList;
+
+fn main() {
+    let mut list = VecLinkedList::with_capacity(8);
+    let ten = list.push_back(10);
+    let twenty = list.push_back(20);
+    list.push_front(5);
+    list.display();
+    list.check_links();
+
+    // Handles keep pointing at their node while others come and go
+    if let Ok(fifteen) = list.insert_after_id(ten, 15) {
+        println!("Next after 10: {:?}", list.next_id(ten).and_then(|id| list.get_by_id(id)));
+        if let Some(value) = list.get_by_id_mut(fifteen) {
+            *value += 1;
+        }
+    }
+    list.pop_front();
+    println!("Still 20: {:?}", list.get_by_id(twenty));
+    list.display();
+
+    // A removed node's slot is reused, its old handle does not match the new node
+    if let Ok(removed) = list.remove_by_id(ten) {
+        println!("Removed: {}", removed);
+    }
+    list.push_back(30);
+    println!("Stale handle: {:?} / {}", list.get_by_id(ten), list.contains_id(ten));
+    println!("Capacity: {}", list.capacity());
+    list.display();
+    list.check_links();
+
+    let mut edited: VecLinkedList<_> = (1..=8).collect();
+    edited.retain(|value| value % 3 != 0);
+    edited.reverse();
+    if let Ok(tail) = edited.split_off(3) {
+        println!("Kept: {} / split off: {}", edited, tail);
+        edited.splice_at(1, tail).ok();
+    }
+    edited.sort();
+    edited.insert_sorted(6);
+    println!("Sorted: {} (is sorted: {})", edited, edited.is_sorted());
+    edited.check_links();
+
+    let mut cursor = edited.cursor_back_mut();
+    cursor.move_prev();
+    if let Some(value) = cursor.current() {
+        *value *= 10;
+    }
+    cursor.insert_after(70);
+    println!("Edited: {} {:?}", edited, edited);
+}
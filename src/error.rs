@@ -0,0 +1,26 @@
+use std::fmt;
+
+/// Errors returned by the fallible list operations
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListError {
+    /// The given index is past the end of the list
+    IndexOutOfBounds { index: usize, len: usize },
+    /// No node holds the requested data
+    NotFound,
+    /// The operation needs at least one node
+    Empty,
+}
+
+impl fmt::Display for ListError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ListError::IndexOutOfBounds { index, len } => {
+                write!(f, "index {} out of range for list of length {}", index, len)
+            }
+            ListError::NotFound => write!(f, "node not found in the list"),
+            ListError::Empty => write!(f, "list is empty"),
+        }
+    }
+}
+
+impl std::error::Error for ListError {}
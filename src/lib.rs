@@ -0,0 +1,4 @@
+pub mod error;
+pub mod linked_list;
+
+pub use error::ListError;
@@ -0,0 +1,565 @@
+use std::mem;
+
+use crate::ListError;
+use super::{DoublyLinkedList, SinglyLinkedList};
+
+/// A cycle found by [`floyd_cycle`] or [`brent_cycle`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle<N> {
+    /// The first node that is part of the cycle
+    pub start: N,
+    /// How many steps it takes to get from the head to `start`
+    pub start_index: usize,
+    /// How many nodes are in the cycle
+    pub len: usize,
+}
+
+/// Check if following `next` from `head` ever runs in a circle
+/// 
+/// The lists in this crate own their nodes so they can never contain a cycle,
+/// the cycle functions work on any sequence given by a successor function
+/// instead, e.g. a table of `next` indices.
+/// 
+/// args:
+/// * `head`: the first node
+/// * `next`: returns the node after the given one, `None` at the end
+pub fn has_cycle<N, F>(head: N, next: F) -> bool
+where
+    N: Copy + PartialEq,
+    F: FnMut(N) -> Option<N>
+{
+    floyd_cycle(head, next).is_some()
+}
+
+/// Floyd's "tortoise and hare" cycle detection, O(n) time and O(1) memory
+/// 
+/// args:
+/// * `head`: the first node
+/// * `next`: returns the node after the given one, `None` at the end
+/// 
+/// return:
+/// * `Option<Cycle<N>>` -> The start and length of the cycle, `None` if the sequence ends
+pub fn floyd_cycle<N, F>(head: N, mut next: F) -> Option<Cycle<N>>
+where
+    N: Copy + PartialEq,
+    F: FnMut(N) -> Option<N>
+{
+    // The hare moves two steps for every step of the tortoise, if there is
+    // a cycle it catches up with the tortoise somewhere inside it
+    let mut tortoise = head;
+    let mut hare = head;
+    loop {
+        tortoise = next(tortoise)?;
+        let step = next(hare)?;
+        hare = next(step)?;
+        if tortoise == hare {
+            break;
+        }
+    }
+
+    // The meeting point is as far from the cycle start as the head is,
+    // so moving one step at a time from both ends meets at the start
+    let mut start_index = 0;
+    tortoise = head;
+    while tortoise != hare {
+        tortoise = next(tortoise)?;
+        hare = next(hare)?;
+        start_index += 1;
+    }
+
+    // Walk around the cycle once to measure it
+    let mut len = 1;
+    hare = next(tortoise)?;
+    while hare != tortoise {
+        hare = next(hare)?;
+        len += 1;
+    }
+
+    Some(Cycle { start: tortoise, start_index, len })
+}
+
+/// Brent's cycle detection, calls `next` fewer times than [`floyd_cycle`]
+/// 
+/// args:
+/// * `head`: the first node
+/// * `next`: returns the node after the given one, `None` at the end
+/// 
+/// return:
+/// * `Option<Cycle<N>>` -> The start and length of the cycle, `None` if the sequence ends
+pub fn brent_cycle<N, F>(head: N, mut next: F) -> Option<Cycle<N>>
+where
+    N: Copy + PartialEq,
+    F: FnMut(N) -> Option<N>
+{
+    // The tortoise jumps to the hare every power of two steps, once both
+    // are inside the cycle the hare comes back around within one power
+    let mut power = 1;
+    let mut len = 1;
+    let mut tortoise = head;
+    let mut hare = next(head)?;
+    while tortoise != hare {
+        if power == len {
+            tortoise = hare;
+            power *= 2;
+            len = 0;
+        }
+        hare = next(hare)?;
+        len += 1;
+    }
+
+    // Start the hare `len` steps ahead, both meet at the cycle start
+    tortoise = head;
+    hare = head;
+    for _ in 0..len {
+        hare = next(hare)?;
+    }
+
+    let mut start_index = 0;
+    while tortoise != hare {
+        tortoise = next(tortoise)?;
+        hare = next(hare)?;
+        start_index += 1;
+    }
+
+    Some(Cycle { start: tortoise, start_index, len })
+}
+
+/// Find the middle element with a slow and a fast pointer, in one pass
+/// 
+/// Works on anything with a cloneable iterator, e.g. `&SinglyLinkedList<T>`
+/// or `&DoublyLinkedList<T>`.
+/// 
+/// return:
+/// * `Option<I::Item>` -> The element at `len / 2` (the second one of two middles),
+///   `None` when empty
+pub fn middle<I>(items: I) -> Option<I::Item>
+where
+    I: IntoIterator,
+    I::IntoIter: Clone
+{
+    let mut slow = items.into_iter();
+    let mut fast = slow.clone();
+    while fast.next().is_some() && fast.next().is_some() {
+        slow.next();
+    }
+    slow.next()
+}
+
+/// Find the k-th element from the end with two pointers `k` apart, in one pass
+/// 
+/// args:
+/// * `k`: 1 is the last element
+/// 
+/// return:
+/// * `Option<I::Item>` -> `None` if `k` is 0 or larger than the length
+pub fn kth_from_end<I>(items: I, k: usize) -> Option<I::Item>
+where
+    I: IntoIterator,
+    I::IntoIter: Clone
+{
+    if k == 0 {
+        return None;
+    }
+
+    let mut trail = items.into_iter();
+    let mut lead = trail.clone();
+    lead.nth(k - 1)?;
+    while lead.next().is_some() {
+        trail.next();
+    }
+    trail.next()
+}
+
+/// Check if a list reads the same both ways by walking in from both ends
+/// 
+/// Needs a double-ended iterator, e.g. `&DoublyLinkedList<T>`. Use
+/// [`is_palindrome`] for a singly linked list.
+pub fn is_palindrome_by_ends<I>(items: I) -> bool
+where
+    I: IntoIterator,
+    I::IntoIter: DoubleEndedIterator + ExactSizeIterator,
+    I::Item: PartialEq
+{
+    let mut iter = items.into_iter();
+    for _ in 0..iter.len() / 2 {
+        if iter.next() != iter.next_back() {
+            return false;
+        }
+    }
+    true
+}
+
+/// The splicing operations the list rearranging algorithms are built from
+/// 
+/// They only relink nodes, the data is never moved or cloned.
+pub trait SpliceList<T>: Default {
+    /// The number of elements in the list
+    fn len(&self) -> usize;
+
+    /// Check if the list is empty
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get a reference to the element at an index
+    fn get(&self, index: usize) -> Option<&T>;
+
+    /// Split the list in two, the returned list starts at `at`
+    fn split_off(&mut self, at: usize) -> Result<Self, ListError>;
+
+    /// Move every node of `other` to the back of this list
+    fn append(&mut self, other: &mut Self);
+
+    /// Move every node of `other` to the front of this list
+    fn prepend(&mut self, other: Self);
+
+    /// Reverse the list
+    fn reverse(&mut self);
+}
+
+impl<T> SpliceList<T> for SinglyLinkedList<T> {
+    fn len(&self) -> usize {
+        SinglyLinkedList::len(self)
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        SinglyLinkedList::get(self, index)
+    }
+
+    fn split_off(&mut self, at: usize) -> Result<Self, ListError> {
+        SinglyLinkedList::split_off(self, at)
+    }
+
+    fn append(&mut self, other: &mut Self) {
+        SinglyLinkedList::append(self, other);
+    }
+
+    fn prepend(&mut self, other: Self) {
+        SinglyLinkedList::prepend(self, other);
+    }
+
+    fn reverse(&mut self) {
+        SinglyLinkedList::reverse(self);
+    }
+}
+
+impl<T> SpliceList<T> for DoublyLinkedList<T> {
+    fn len(&self) -> usize {
+        DoublyLinkedList::len(self)
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        DoublyLinkedList::get(self, index)
+    }
+
+    fn split_off(&mut self, at: usize) -> Result<Self, ListError> {
+        DoublyLinkedList::split_off(self, at)
+    }
+
+    fn append(&mut self, other: &mut Self) {
+        DoublyLinkedList::append(self, other);
+    }
+
+    fn prepend(&mut self, other: Self) {
+        DoublyLinkedList::prepend(self, other);
+    }
+
+    fn reverse(&mut self) {
+        DoublyLinkedList::reverse(self);
+    }
+}
+
+/// Check if a list reads the same both ways without a double-ended iterator
+/// 
+/// Reverses the second half in place, compares it with the first half and
+/// puts everything back. O(n) time and O(1) extra memory.
+pub fn is_palindrome<T, L>(list: &mut L) -> bool
+where
+    T: PartialEq,
+    L: SpliceList<T>,
+    for<'a> &'a L: IntoIterator<Item = &'a T>
+{
+    // The middle element of an odd length list stays in the first half
+    let mut back = list.split_off(list.len().div_ceil(2)).expect("split point is in range");
+    back.reverse();
+    let front: &L = list;
+    let result = front.into_iter().zip(&back).all(|(a, b)| a == b);
+    back.reverse();
+    list.append(&mut back);
+    result
+}
+
+/// Reverse every group of `k` nodes, a shorter last group is reversed as well
+/// 
+/// O(n), a `k` of 0 or 1 leaves the list as it is
+pub fn reverse_in_groups<T, L>(list: &mut L, k: usize)
+where
+    L: SpliceList<T>
+{
+    if k < 2 {
+        return;
+    }
+
+    let mut rest = mem::take(list);
+    let mut groups = Vec::new();
+    while !rest.is_empty() {
+        let after = rest.split_off(k.min(rest.len())).expect("group end is in range");
+        let mut group = mem::replace(&mut rest, after);
+        group.reverse();
+        groups.push(group);
+    }
+
+    // Putting the groups back from the last one only ever walks the group itself
+    for group in groups.into_iter().rev() {
+        list.prepend(group);
+    }
+}
+
+/// Swap every two adjacent nodes, `1 2 3 4 5` becomes `2 1 4 3 5`
+pub fn pairwise_swap<T, L>(list: &mut L)
+where
+    L: SpliceList<T>
+{
+    reverse_in_groups(list, 2);
+}
+
+/// Rotate the list left by `k`, the first `k` nodes move to the back
+/// 
+/// `k` larger than the length wraps around
+pub fn rotate_left<T, L>(list: &mut L, k: usize)
+where
+    L: SpliceList<T>
+{
+    if list.is_empty() {
+        return;
+    }
+
+    let back = list.split_off(k % list.len()).expect("split point is in range");
+    list.prepend(back);
+}
+
+/// Rotate the list right by `k`, the last `k` nodes move to the front
+/// 
+/// `k` larger than the length wraps around
+pub fn rotate_right<T, L>(list: &mut L, k: usize)
+where
+    L: SpliceList<T>
+{
+    if list.is_empty() {
+        return;
+    }
+
+    let k = k % list.len();
+    rotate_left(list, list.len() - k);
+}
+
+/// Move every node smaller than `pivot` in front of the others, keeping the
+/// relative order on both sides
+/// 
+/// O(n), each node is split off the front and pushed onto one of two lists
+pub fn partition<T, L>(list: &mut L, pivot: &T)
+where
+    T: PartialOrd,
+    L: SpliceList<T>
+{
+    let mut rest = mem::take(list);
+    let mut smaller = L::default();
+    let mut larger = L::default();
+
+    while !rest.is_empty() {
+        let after = rest.split_off(1).expect("list is not empty");
+        let node = mem::replace(&mut rest, after);
+        let is_smaller = node.get(0).is_some_and(|value| value < pivot);
+
+        // Prepending a single node is O(1) for every list, so both sides are
+        // built back to front and reversed at the end
+        if is_smaller {
+            smaller.prepend(node);
+        } else {
+            larger.prepend(node);
+        }
+    }
+
+    smaller.reverse();
+    larger.reverse();
+    smaller.append(&mut larger);
+    *list = smaller;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Apply `$rearrange` to a singly and a doubly list built from `$values`,
+    /// both have to end up holding `$expected`
+    macro_rules! assert_rearranged {
+        ($values:expr, $rearrange:expr, $expected:expr) => {{
+            let mut singly: SinglyLinkedList<_> = $values.into_iter().collect();
+            let mut doubly: DoublyLinkedList<_> = $values.into_iter().collect();
+            ($rearrange)(&mut singly);
+            ($rearrange)(&mut doubly);
+            doubly.check_links();
+
+            let expected: Vec<_> = $expected.into_iter().collect();
+            assert_eq!(singly.len(), expected.len());
+            assert_eq!(singly.into_iter().collect::<Vec<_>>(), expected);
+            assert_eq!(doubly.into_iter().collect::<Vec<_>>(), expected);
+        }};
+    }
+
+    /// Follow a table of `next` indices
+    fn follow(next: &[Option<usize>]) -> impl FnMut(usize) -> Option<usize> + '_ {
+        |index| next[index]
+    }
+
+    #[test]
+    fn cycle_detection_without_a_cycle() {
+        for next in [&[None][..], &[Some(1), Some(2), None]] {
+            assert!(!has_cycle(0, follow(next)));
+            assert_eq!(floyd_cycle(0, follow(next)), None);
+            assert_eq!(brent_cycle(0, follow(next)), None);
+        }
+    }
+
+    #[test]
+    fn cycle_detection_with_a_self_loop_at_the_head() {
+        let next = [Some(0)];
+        let expected = Some(Cycle { start: 0, start_index: 0, len: 1 });
+        assert!(has_cycle(0, follow(&next)));
+        assert_eq!(floyd_cycle(0, follow(&next)), expected);
+        assert_eq!(brent_cycle(0, follow(&next)), expected);
+    }
+
+    #[test]
+    fn cycle_detection_with_a_cycle_starting_mid_sequence() {
+        // 0 -> 1 -> 2 -> 3 -> 4 -> 5 -> back to 2
+        let next = [Some(1), Some(2), Some(3), Some(4), Some(5), Some(2)];
+        let expected = Some(Cycle { start: 2, start_index: 2, len: 4 });
+        assert_eq!(floyd_cycle(0, follow(&next)), expected);
+        assert_eq!(brent_cycle(0, follow(&next)), expected);
+
+        // The whole sequence is the cycle
+        let next = [Some(1), Some(2), Some(0)];
+        let expected = Some(Cycle { start: 0, start_index: 0, len: 3 });
+        assert_eq!(floyd_cycle(0, follow(&next)), expected);
+        assert_eq!(brent_cycle(0, follow(&next)), expected);
+    }
+
+    #[test]
+    fn cycle_detection_matches_a_brute_force_walk() {
+        // Every table of up to 5 nodes where each `next` is the end or any node
+        for nodes in 1..=5usize {
+            let choices = nodes + 1;
+            for code in 0..choices.pow(nodes as u32) {
+                let next: Vec<_> = (0..nodes)
+                    .map(|node| match code / choices.pow(node as u32) % choices {
+                        0 => None,
+                        target => Some(target - 1),
+                    })
+                    .collect();
+
+                // Walk until a node repeats, its first visit is the cycle start
+                let mut seen = vec![None; nodes];
+                let mut current = Some(0);
+                let mut steps = 0;
+                let expected = loop {
+                    let Some(node) = current else { break None };
+                    if let Some(start_index) = seen[node] {
+                        break Some(Cycle { start: node, start_index, len: steps - start_index });
+                    }
+                    seen[node] = Some(steps);
+                    current = next[node];
+                    steps += 1;
+                };
+
+                assert_eq!(floyd_cycle(0, follow(&next)), expected, "{:?}", next);
+                assert_eq!(brent_cycle(0, follow(&next)), expected, "{:?}", next);
+            }
+        }
+    }
+
+    #[test]
+    fn middle_of_short_and_odd_lists() {
+        let lists: [SinglyLinkedList<i32>; 5] = [
+            SinglyLinkedList::new(),
+            [1].into_iter().collect(),
+            [1, 2].into_iter().collect(),
+            (1..=4).collect(),
+            (1..=5).collect(),
+        ];
+        let middles: Vec<_> = lists.iter().map(|list| middle(list).copied()).collect();
+        assert_eq!(middles, [None, Some(1), Some(2), Some(3), Some(3)]);
+
+        let doubly: DoublyLinkedList<_> = (1..=5).collect();
+        assert_eq!(middle(&doubly), Some(&3));
+        assert_eq!(middle(Vec::<i32>::new()), None);
+    }
+
+    #[test]
+    fn kth_from_end_bounds() {
+        let empty = SinglyLinkedList::<i32>::new();
+        assert_eq!(kth_from_end(&empty, 1), None);
+
+        let one: SinglyLinkedList<_> = [7].into_iter().collect();
+        assert_eq!(kth_from_end(&one, 1), Some(&7));
+        assert_eq!(kth_from_end(&one, 2), None);
+
+        let two: DoublyLinkedList<_> = [1, 2].into_iter().collect();
+        assert_eq!(kth_from_end(&two, 1), Some(&2));
+        assert_eq!(kth_from_end(&two, 2), Some(&1));
+
+        let odd: SinglyLinkedList<_> = (1..=5).collect();
+        assert_eq!(kth_from_end(&odd, 0), None);
+        assert_eq!(kth_from_end(&odd, 1), Some(&5));
+        assert_eq!(kth_from_end(&odd, 3), Some(&3));
+        assert_eq!(kth_from_end(&odd, 5), Some(&1));
+        assert_eq!(kth_from_end(&odd, 6), None);
+    }
+
+    #[test]
+    fn is_palindrome_restores_the_list() {
+        let cases = [("", true), ("a", true), ("abba", true), ("racecar", true), ("abca", false), ("abc", false)];
+        for (word, expected) in cases {
+            let mut singly: SinglyLinkedList<_> = word.chars().collect();
+            let mut doubly: DoublyLinkedList<_> = word.chars().collect();
+            let before = singly.clone();
+
+            assert_eq!(is_palindrome(&mut singly), expected, "{}", word);
+            assert_eq!(is_palindrome(&mut doubly), expected, "{}", word);
+            assert_eq!(is_palindrome_by_ends(&doubly), expected, "{}", word);
+
+            assert_eq!(singly, before);
+            assert_eq!(singly.len(), word.len());
+            doubly.check_links();
+            assert!(doubly.iter().eq(before.iter()));
+        }
+    }
+
+    #[test]
+    fn reverse_in_groups_with_a_partial_last_group() {
+        assert_rearranged!(1..=8, |list| reverse_in_groups(list, 3), [3, 2, 1, 6, 5, 4, 8, 7]);
+        assert_rearranged!(1..=4, |list| reverse_in_groups(list, 9), [4, 3, 2, 1]);
+        assert_rearranged!(1..=4, |list| reverse_in_groups(list, 1), [1, 2, 3, 4]);
+        assert_rearranged!(1..=4, |list| reverse_in_groups(list, 0), [1, 2, 3, 4]);
+        assert_rearranged!(1..=5, pairwise_swap, [2, 1, 4, 3, 5]);
+    }
+
+    #[test]
+    fn rotate_wraps_when_k_is_at_least_len() {
+        assert_rearranged!(1..=5, |list| rotate_left(list, 2), [3, 4, 5, 1, 2]);
+        assert_rearranged!(1..=5, |list| rotate_left(list, 5), [1, 2, 3, 4, 5]);
+        assert_rearranged!(1..=5, |list| rotate_left(list, 7), [3, 4, 5, 1, 2]);
+        assert_rearranged!(1..=5, |list| rotate_right(list, 5), [1, 2, 3, 4, 5]);
+        assert_rearranged!(1..=5, |list| rotate_right(list, 7), [4, 5, 1, 2, 3]);
+        assert_rearranged!(0..0, |list| rotate_right(list, 3), []);
+    }
+
+    #[test]
+    fn partition_is_stable_on_both_sides() {
+        // The letters tell equal keys apart, every `(5, _)` is at least the pivot
+        let values = [(3, 'a'), (5, 'b'), (8, 'c'), (1, 'd'), (5, 'e'), (3, 'f'), (10, 'g')];
+        let expected = [(3, 'a'), (1, 'd'), (3, 'f'), (5, 'b'), (8, 'c'), (5, 'e'), (10, 'g')];
+        assert_rearranged!(values, |list| partition(list, &(5, '\0')), expected);
+        assert_rearranged!(values, |list| partition(list, &(0, '\0')), values);
+    }
+}
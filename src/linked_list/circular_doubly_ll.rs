@@ -0,0 +1,698 @@
+use std::{cell::RefCell, fmt, hash::{Hash, Hasher}, marker::PhantomData, rc::Rc};
+
+use crate::ListError;
+
+#[derive(Debug)]
+struct Node<T> {
+    data: T,
+    next: Option<Rc<RefCell<Node<T>>>>,
+    prev: Option<Rc<RefCell<Node<T>>>>,
+}
+
+/// Code structure for the Circular Doubly Linked List
+///
+/// Every node links both ways and the ring is closed in both directions, so
+/// the tail is always `head.prev` and both ends are reachable in O(1)
+pub struct CircularDoublyLinkedList<T> {
+    head: Option<Rc<RefCell<Node<T>>>>,
+    len: usize,
+}
+
+impl<T> Default for CircularDoublyLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// The ring holds strong `Rc`s in both directions, so the default drop would
+// leak every node. Unlink the nodes first.
+impl<T> Drop for CircularDoublyLinkedList<T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T> CircularDoublyLinkedList<T> {
+    /// Create a new empty circular doubly linked list
+    pub fn new() -> Self {
+        Self { head: None, len: 0 }
+    }
+
+    /// Insert a element at the front/start of the list in O(1)
+    ///
+    /// args:
+    /// * `data`: the data that will be inserted `(T)`
+    pub fn push_front(&mut self, data: T) {
+        let new_node = self.link_before_head(data);
+        self.head = Some(new_node);
+    }
+
+    /// Insert a element at the back/end of the list in O(1)
+    ///
+    /// args:
+    /// * `data`: the data that will be inserted `(T)`
+    pub fn push_back(&mut self, data: T) {
+        self.link_before_head(data);
+    }
+
+    /// Remove the element at the front/start of the list in O(1)
+    ///
+    /// return:
+    /// * `Option<T>` -> The removed data, `None` if the list is empty
+    pub fn pop_front(&mut self) -> Option<T> {
+        let head = self.head.clone()?;
+        Some(self.unlink(head))
+    }
+
+    /// Remove the element at the back/end of the list in O(1)
+    ///
+    /// return:
+    /// * `Option<T>` -> The removed data, `None` if the list is empty
+    pub fn pop_back(&mut self) -> Option<T> {
+        let tail = self.tail()?;
+        Some(self.unlink(tail))
+    }
+
+    /// Get the element at the front/start of the list
+    pub fn front(&self) -> Option<&T> {
+        self.iter().next()
+    }
+
+    /// Get the element at the back/end of the list
+    pub fn back(&self) -> Option<&T> {
+        self.iter().next_back()
+    }
+
+    /// Move the start of the ring `n` steps forward
+    ///
+    /// The second element becomes the head after `rotate_left(1)`. Walks at
+    /// most `len / 2` nodes, going backwards when that is shorter.
+    pub fn rotate_left(&mut self, n: usize) {
+        if self.len < 2 {
+            return;
+        }
+
+        let steps = n % self.len;
+        self.head = self.node_at(steps);
+    }
+
+    /// Move the start of the ring `n` steps backward
+    ///
+    /// The last element becomes the head after `rotate_right(1)`
+    pub fn rotate_right(&mut self, n: usize) {
+        if self.len < 2 {
+            return;
+        }
+
+        let steps = n % self.len;
+        self.head = self.node_at((self.len - steps) % self.len);
+    }
+
+    /// Splice all the elements of `other` into this list in O(1) relinking
+    ///
+    /// After the splice the first element of `other` is at position `index`,
+    /// so `0` puts `other` in front and `len()` puts it at the back. Finding the
+    /// position walks at most `len / 2` nodes.
+    ///
+    /// args:
+    /// * `index`: the position the spliced ring starts at
+    ///
+    /// * `other`: the list that will be spliced in
+    ///
+    /// return:
+    /// * `Err(ListError::IndexOutOfBounds)` -> If `index` is greater than `len()`
+    pub fn splice(&mut self, index: usize, mut other: Self) -> Result<(), ListError> {
+        if index > self.len {
+            return Err(ListError::IndexOutOfBounds { index, len: self.len });
+        }
+
+        let other_head = match other.head.take() {
+            Some(head) => head,
+            None => return Ok(()),
+        };
+        let other_len = std::mem::take(&mut other.len);
+
+        // The spliced ring goes in between `prev` and `next`, at the back
+        // `next` wraps around to head
+        let next = match self.node_at(index % self.len.max(1)) {
+            Some(node) => node,
+            None => {
+                // Empty list, just take over the other ring
+                self.head = Some(other_head);
+                self.len = other_len;
+                return Ok(());
+            }
+        };
+
+        let prev = next.borrow().prev.clone().expect("ring is closed");
+        let other_tail = other_head.borrow().prev.clone().expect("ring is closed");
+
+        prev.borrow_mut().next = Some(other_head.clone());
+        other_head.borrow_mut().prev = Some(prev);
+        other_tail.borrow_mut().next = Some(next.clone());
+        next.borrow_mut().prev = Some(other_tail);
+
+        if index == 0 {
+            self.head = Some(other_head);
+        }
+        self.len += other_len;
+        Ok(())
+    }
+
+    /// Print all the items/data of the list
+    pub fn display(&self)
+    where T: std::fmt::Display
+    {
+        println!("{}", self);
+    }
+
+    /// Get the number of items in the list in O(1)
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check if the list is empty or not
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    /// Remove every element from the list
+    ///
+    /// Nodes are unlinked one at a time from the front, so the ring is
+    /// broken in both directions and every node is freed
+    pub fn clear(&mut self) {
+        while !self.is_empty() {
+            self.pop_front();
+        }
+    }
+
+    /// Iterate over references to the data, going once around the ring from head
+    ///
+    /// Also iterates backwards from the tail through `.rev()`
+    pub fn iter(&self) -> Iter<'_, T> {
+        let tail = self.head.as_ref().map(|head| {
+            // SAFETY: `&self` is held for as long as the iterator lives
+            let head = unsafe { &*head.as_ptr() };
+            head.prev.as_deref().expect("ring is closed")
+        });
+
+        Iter {
+            head: self.head.as_deref(),
+            tail,
+            len: self.len
+        }
+    }
+
+    /// Iterate over mutable references to the data, going once around the ring from head
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let tail = self.head.as_ref().map(|head| {
+            // SAFETY: `&mut self` is held for as long as the iterator lives
+            let head = unsafe { &*head.as_ptr() };
+            head.prev.as_deref().expect("ring is closed")
+        });
+
+        IterMut {
+            head: self.head.as_deref(),
+            tail,
+            len: self.len,
+            _marker: PhantomData
+        }
+    }
+
+    /// Check that every `next`/`prev` pair agrees and that `len` matches the ring
+    ///
+    /// Walks the whole ring and panics on the first broken link. Only runs in
+    /// debug builds, in release builds it returns immediately.
+    pub fn check_links(&self) {
+        if !cfg!(debug_assertions) {
+            return;
+        }
+
+        let head = match &self.head {
+            Some(head) => head,
+            None => {
+                assert_eq!(self.len, 0, "empty list has non-zero len");
+                return;
+            }
+        };
+
+        let mut current = head.clone();
+        for index in 0..self.len {
+            let next = current.borrow().next.clone().expect("ring is closed");
+            let prev = next.borrow().prev.clone().expect("ring is closed");
+            assert!(
+                Rc::ptr_eq(&prev, &current),
+                "node {} does not link back to node {}", index + 1, index
+            );
+            current = next;
+        }
+
+        assert!(Rc::ptr_eq(&current, head), "len does not match the ring size");
+    }
+
+    /// Get the last node of the ring
+    fn tail(&self) -> Option<Rc<RefCell<Node<T>>>> {
+        self.head.as_ref().and_then(|head| head.borrow().prev.clone())
+    }
+
+    /// Get the node at `index`, walking from whichever side of head is closer
+    fn node_at(&self, index: usize) -> Option<Rc<RefCell<Node<T>>>> {
+        if index >= self.len {
+            return None;
+        }
+
+        let mut current = self.head.clone()?;
+        if index <= self.len / 2 {
+            for _ in 0..index {
+                let next = current.borrow().next.clone()?;
+                current = next;
+            }
+        } else {
+            for _ in index..self.len {
+                let prev = current.borrow().prev.clone()?;
+                current = prev;
+            }
+        }
+        Some(current)
+    }
+
+    /// Link a new node in between the tail and head
+    ///
+    /// Without moving `head` the new node ends up as the tail. An empty list
+    /// gets a node that links to itself both ways and becomes the head.
+    fn link_before_head(&mut self, data: T) -> Rc<RefCell<Node<T>>> {
+        let new_node = Rc::new(RefCell::new(Node {
+            data,
+            next: None,
+            prev: None
+        }));
+
+        match self.tail() {
+            Some(tail) => {
+                let head = self.head.clone().expect("list is not empty");
+                {
+                    let mut node = new_node.borrow_mut();
+                    node.next = Some(head.clone());
+                    node.prev = Some(tail.clone());
+                }
+                tail.borrow_mut().next = Some(new_node.clone());
+                head.borrow_mut().prev = Some(new_node.clone());
+            }
+            None => {
+                {
+                    let mut node = new_node.borrow_mut();
+                    node.next = Some(new_node.clone());
+                    node.prev = Some(new_node.clone());
+                }
+                self.head = Some(new_node.clone());
+            }
+        }
+
+        self.len += 1;
+        new_node
+    }
+
+    /// Unlink `node` from the ring and take its data, moving head on if needed
+    fn unlink(&mut self, node: Rc<RefCell<Node<T>>>) -> T {
+        let (next, prev) = {
+            let mut node = node.borrow_mut();
+            (node.next.take(), node.prev.take())
+        };
+
+        if self.len == 1 {
+            // Both links point back at `node` itself
+            drop((next, prev));
+            self.head = None;
+        } else {
+            let next = next.expect("ring is closed");
+            let prev = prev.expect("ring is closed");
+            prev.borrow_mut().next = Some(next.clone());
+            next.borrow_mut().prev = Some(prev);
+
+            if self.head.as_ref().is_some_and(|head| Rc::ptr_eq(head, &node)) {
+                self.head = Some(next);
+            }
+        }
+        self.len -= 1;
+
+        match Rc::try_unwrap(node) {
+            Ok(node) => node.into_inner().data,
+            Err(_) => unreachable!("unlinked node is still referenced"),
+        }
+    }
+}
+
+// The iterators below read through the `RefCell`s without a guard, the same
+// way and for the same reason as the ones of `CircularLinkedList`, see the
+// comment above `circular_ll::Iter`. Coming from both ends, the shared `len`
+// counter also stops `next` and `next_back` from handing out a node twice.
+
+/// Borrowing iterator returned by [`CircularDoublyLinkedList::iter`]
+pub struct Iter<'a, T> {
+    head: Option<&'a RefCell<Node<T>>>,
+    tail: Option<&'a RefCell<Node<T>>>,
+    len: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.head.map(|cell| {
+            // SAFETY: see the comment above `Iter`
+            let node = unsafe { &*cell.as_ptr() };
+            self.head = node.next.as_deref();
+            self.len -= 1;
+            &node.data
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.tail.map(|cell| {
+            // SAFETY: see the comment above `Iter`
+            let node = unsafe { &*cell.as_ptr() };
+            self.tail = node.prev.as_deref();
+            self.len -= 1;
+            &node.data
+        })
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+/// Mutable iterator returned by [`CircularDoublyLinkedList::iter_mut`]
+pub struct IterMut<'a, T> {
+    head: Option<&'a RefCell<Node<T>>>,
+    tail: Option<&'a RefCell<Node<T>>>,
+    len: usize,
+    _marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.head.map(|cell| {
+            let node = cell.as_ptr();
+            self.len -= 1;
+            // SAFETY: see the comment above `Iter`
+            unsafe {
+                self.head = (*node).next.as_deref();
+                &mut (*node).data
+            }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.tail.map(|cell| {
+            let node = cell.as_ptr();
+            self.len -= 1;
+            // SAFETY: see the comment above `Iter`
+            unsafe {
+                self.tail = (*node).prev.as_deref();
+                &mut (*node).data
+            }
+        })
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+/// Consuming iterator returned by [`CircularDoublyLinkedList::into_iter`]
+pub struct IntoIter<T>(CircularDoublyLinkedList<T>);
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len, Some(self.0.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.pop_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> IntoIterator for CircularDoublyLinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a CircularDoublyLinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut CircularDoublyLinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T> Extend<T> for CircularDoublyLinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for data in iter {
+            self.push_back(data);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for CircularDoublyLinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T: Clone> Clone for CircularDoublyLinkedList<T> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: PartialEq> PartialEq for CircularDoublyLinkedList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for CircularDoublyLinkedList<T> {}
+
+impl<T: Hash> Hash for CircularDoublyLinkedList<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        for data in self.iter() {
+            data.hash(state);
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for CircularDoublyLinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for CircularDoublyLinkedList<T> {
+    /// Renders the list once around the ring as `a <-> b <-> (back to start)`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "Empty list");
+        }
+
+        for data in self.iter() {
+            write!(f, "{} <-> ", data)?;
+        }
+        write!(f, "(back to start)")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic::{self, AssertUnwindSafe};
+
+    use crate::linked_list::test_utils;
+    use super::*;
+
+    /// Check the links and compare the ring with `expected`, going both ways from head
+    fn assert_ring(list: &CircularDoublyLinkedList<i32>, expected: &[i32]) {
+        list.check_links();
+        let backwards: Vec<_> = expected.iter().rev().copied().collect();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), expected);
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), backwards);
+        assert_eq!(list.len(), expected.len());
+        assert_eq!(list.front(), expected.first());
+        assert_eq!(list.back(), expected.last());
+    }
+
+    #[test]
+    fn pushes_and_pops_at_both_ends() {
+        let mut list = CircularDoublyLinkedList::new();
+        assert_eq!(list.pop_front(), None);
+        assert_eq!(list.pop_back(), None);
+        assert_ring(&list, &[]);
+
+        list.push_back(2);
+        assert_ring(&list, &[2]);
+        list.push_front(1);
+        list.push_back(3);
+        list.push_front(0);
+        assert_ring(&list, &[0, 1, 2, 3]);
+
+        assert_eq!(list.pop_back(), Some(3));
+        assert_ring(&list, &[0, 1, 2]);
+        assert_eq!(list.pop_front(), Some(0));
+        assert_ring(&list, &[1, 2]);
+        assert_eq!(list.pop_front(), Some(1));
+        assert_ring(&list, &[2]);
+        assert_eq!(list.pop_back(), Some(2));
+        assert_ring(&list, &[]);
+    }
+
+    #[test]
+    fn splice_at_front_back_and_middle() {
+        for index in 0..=3 {
+            let mut list: CircularDoublyLinkedList<_> = (0..3).collect();
+            assert!(list.splice(index, [10, 11].into_iter().collect()).is_ok());
+            let mut expected = vec![0, 1, 2];
+            expected.splice(index..index, [10, 11]);
+            assert_ring(&list, &expected);
+        }
+
+        let mut list = CircularDoublyLinkedList::new();
+        assert!(list.splice(0, [1, 2].into_iter().collect()).is_ok());
+        assert_ring(&list, &[1, 2]);
+        assert!(list.splice(1, CircularDoublyLinkedList::new()).is_ok());
+        assert!(list.splice(1, [5].into_iter().collect()).is_ok());
+        assert_ring(&list, &[1, 5, 2]);
+
+        assert_eq!(
+            list.splice(4, [9].into_iter().collect()),
+            Err(ListError::IndexOutOfBounds { index: 4, len: 3 })
+        );
+        assert_ring(&list, &[1, 5, 2]);
+    }
+
+    #[test]
+    fn rotations_match_a_rotated_vec() {
+        for len in 0..5 {
+            for n in [0, 1, 2, len, len + 1, 3 * len + 2] {
+                let expected: Vec<_> = (0..len as i32).collect();
+
+                let mut list: CircularDoublyLinkedList<_> = expected.iter().copied().collect();
+                list.rotate_left(n);
+                let mut rotated = expected.clone();
+                rotated.rotate_left(n.checked_rem(len).unwrap_or(0));
+                assert_ring(&list, &rotated);
+
+                let mut list: CircularDoublyLinkedList<_> = expected.iter().copied().collect();
+                list.rotate_right(n);
+                let mut rotated = expected.clone();
+                rotated.rotate_right(n.checked_rem(len).unwrap_or(0));
+                assert_ring(&list, &rotated);
+            }
+        }
+
+        // Rotating back and forth ends up where it started
+        let mut list: CircularDoublyLinkedList<_> = (0..5).collect();
+        list.rotate_left(3);
+        list.rotate_right(8);
+        assert_ring(&list, &[0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn iterators_meet_in_the_middle_of_the_ring() {
+        for len in 0..6 {
+            let mut list: CircularDoublyLinkedList<_> = (0..len).collect();
+            let mut iter = list.iter();
+            let mut front = Vec::new();
+            let mut back: Vec<i32> = Vec::new();
+            while let Some(&value) = iter.next() {
+                front.push(value);
+                back.extend(iter.next_back().copied());
+            }
+            assert_eq!(iter.next_back(), None);
+            back.reverse();
+            front.extend(back);
+            assert_eq!(front, (0..len).collect::<Vec<_>>());
+
+            let mut iter = list.iter_mut();
+            while let Some(value) = iter.next_back() {
+                *value *= 10;
+                if let Some(value) = iter.next() {
+                    *value *= 10;
+                }
+            }
+            let expected: Vec<_> = (0..len).map(|value| value * 10).collect();
+            assert_ring(&list, &expected);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "check_links only runs in debug builds")]
+    fn check_links_catches_a_wrong_len() {
+        let mut list: CircularDoublyLinkedList<_> = (0..3).collect();
+        list.len += 1;
+        let result = panic::catch_unwind(AssertUnwindSafe(|| list.check_links()));
+        // Put the real len back so the list can still be dropped
+        list.len -= 1;
+
+        let message = result.expect_err("check_links should panic").downcast::<&str>();
+        assert_eq!(message.ok().as_deref(), Some(&"len does not match the ring size"));
+    }
+
+    test_utils::leak_tests!(CircularDoublyLinkedList, splice);
+
+    #[test]
+    fn std_traits() {
+        test_utils::check_std_traits::<CircularDoublyLinkedList<i32>>("1 <-> 2 <-> 3 <-> (back to start)", "Empty list");
+    }
+}
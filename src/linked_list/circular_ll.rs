@@ -1,99 +1,1105 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, fmt, hash::{Hash, Hasher}, marker::PhantomData, ops::{Index, IndexMut}, rc::Rc};
+
+use crate::ListError;
 
 #[derive(Debug)]
-struct Node<'a, T> {
-    data: &'a T,
-    next: Option<Rc<RefCell<Node<'a, T>>>>,
+struct Node<T> {
+    data: T,
+    next: Option<Rc<RefCell<Node<T>>>>,
 }
 
 /// Code structure for the Circular Linked List
-struct CircularLinkedList<'a, T> {
-    head: Option<Rc<RefCell<Node<'a, T>>>>,
+/// 
+/// The last node always links back to `head`, and `tail` is kept so that both
+/// ends can be reached in O(1)
+pub struct CircularLinkedList<T> {
+    head: Option<Rc<RefCell<Node<T>>>>,
+    tail: Option<Rc<RefCell<Node<T>>>>,
+    len: usize,
+}
+
+impl<T> Default for CircularLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
-#[allow(dead_code)]
-impl<'a, T> CircularLinkedList<'a, T> {
+// The tail holds a strong `Rc` back to head, so the default drop would leak
+// the whole ring. Unlink the nodes first.
+impl<T> Drop for CircularLinkedList<T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T> CircularLinkedList<T> {
     /// Create a new empty circular linked list
-    fn new() -> Self {
-        Self { head: None }
+    pub fn new() -> Self {
+        Self { head: None, tail: None, len: 0 }
+    }
+
+    /// Insert a element at the front/start of the list in O(1)
+    /// 
+    /// args:
+    /// * `data`: the data that will be inserted `(T)`
+    pub fn push_front(&mut self, data: T) {
+        let new_node = self.link_new(data);
+        self.head = Some(new_node);
+    }
+
+    /// Insert a element at the back/end of the list in O(1)
+    /// 
+    /// args:
+    /// * `data`: the data that will be inserted `(T)`
+    pub fn push_back(&mut self, data: T) {
+        let new_node = self.link_new(data);
+        self.tail = Some(new_node);
+    }
+
+    /// Remove the element at the front/start of the list in O(1)
+    /// 
+    /// return:
+    /// * `Option<T>` -> The removed data, `None` if the list is empty
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.len <= 1 {
+            return self.pop_only();
+        }
+
+        let old_head = self.head.take().expect("list is not empty");
+        let new_head = old_head.borrow_mut().next.take();
+        if let Some(tail) = &self.tail {
+            tail.borrow_mut().next = new_head.clone();
+        }
+        self.head = new_head;
+        self.len -= 1;
+
+        Some(Self::into_data(old_head))
+    }
+
+    /// Remove the element at the back/end of the list
+    /// 
+    /// O(n), the node before the tail has to be found by walking the ring
+    /// 
+    /// return:
+    /// * `Option<T>` -> The removed data, `None` if the list is empty
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.len <= 1 {
+            return self.pop_only();
+        }
+
+        let new_tail = self.node_at(self.len - 2).expect("list has two nodes");
+        let old_tail = self.tail.replace(new_tail.clone()).expect("list is not empty");
+        new_tail.borrow_mut().next = old_tail.borrow_mut().next.take();
+        self.len -= 1;
+
+        Some(Self::into_data(old_tail))
+    }
+
+    /// Insert a element at a specific index
+    /// 
+    /// After the insert the new element is at position `index`, so `0` pushes to
+    /// the front and `len()` pushes to the back.
+    /// 
+    /// args:
+    /// * `index`: the index where the data will be inserted
+    /// 
+    /// * `data`: the data that will be inserted `(T)`
+    /// 
+    /// return:
+    /// * `Err(ListError::IndexOutOfBounds)` -> If `index` is greater than `len()`
+    pub fn insert_at_index(&mut self, index: usize, data: T) -> Result<(), ListError> {
+        if index > self.len {
+            return Err(ListError::IndexOutOfBounds { index, len: self.len });
+        }
+
+        if index == 0 {
+            self.push_front(data);
+            return Ok(());
+        }
+
+        if index == self.len {
+            self.push_back(data);
+            return Ok(());
+        }
+
+        let prev = self.node_at(index - 1).expect("index is in range");
+        Self::link_after(&prev, data);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Insert a element after the first element holding `node`
+    /// 
+    /// args:
+    /// * `node`: the data to search for
+    /// 
+    /// * `data`: the data that will be inserted `(T)`
+    /// 
+    /// return:
+    /// * `Err(ListError::Empty)` | `Err(ListError::NotFound)`
+    pub fn insert_after(&mut self, node: &T, data: T) -> Result<(), ListError>
+    where
+        T: PartialEq
+    {
+        if self.is_empty() {
+            return Err(ListError::Empty);
+        }
+
+        let index = self.iter().position(|value| value == node).ok_or(ListError::NotFound)?;
+        self.insert_at_index(index + 1, data)
     }
 
-    /// Insert a element at the front/start of the list
+    /// Delete the first element holding `data`
     /// 
     /// args:
-    /// * `data`: the data that will be inserted `(&'a T)`
-    fn push_front(&mut self, data: &'a T)
+    /// * `data`: the data to be deleted if found in the list
+    /// 
+    /// return:
+    /// * `Ok(T)` -> The removed data
+    /// * `Err(ListError::Empty)` | `Err(ListError::NotFound)`
+    pub fn delete_at_node(&mut self, data: &T) -> Result<T, ListError>
+    where
+        T: PartialEq
     {
-        let new_node = Rc::new(RefCell::new(Node {
-            data,
-            next: None
-        }));
+        if self.is_empty() {
+            return Err(ListError::Empty);
+        }
 
-        match &self.head {
-            Some(head) => {
-                new_node.borrow_mut().next = Some(head.clone());
+        let index = self.iter().position(|value| value == data).ok_or(ListError::NotFound)?;
+        self.remove_at(index)
+    }
 
-                let mut curr = head.clone();
-                while let Some(value) = curr.clone().borrow().next.clone() {
-                    if Rc::ptr_eq(&value, head) {
-                        break;
-                    }
+    /// Get a reference to the element at a specific index, counting from head
+    /// 
+    /// return:
+    /// * `Option<&T>` -> `None` if `index` is out of range
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.iter().nth(index)
+    }
 
-                    curr = value;
-                }
-                
+    /// Get a mutable reference to the element at a specific index, counting from head
+    /// 
+    /// return:
+    /// * `Option<&mut T>` -> `None` if `index` is out of range
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.iter_mut().nth(index)
+    }
 
-                curr.borrow_mut().next = Some(new_node.clone());
-            }
-            None => {
-                new_node.borrow_mut().next = Some(new_node.clone())
+    /// Overwrite the element at a specific index
+    /// 
+    /// return:
+    /// * `Ok(T)` -> The data that was replaced
+    /// * `Err(ListError::IndexOutOfBounds)` -> If there is no element at `index`
+    pub fn set(&mut self, index: usize, data: T) -> Result<T, ListError> {
+        let len = self.len;
+        self.get_mut(index)
+            .map(|value| std::mem::replace(value, data))
+            .ok_or(ListError::IndexOutOfBounds { index, len })
+    }
+
+    /// Remove the element at a specific index
+    /// 
+    /// return:
+    /// * `Ok(T)` -> The removed data
+    /// * `Err(ListError::IndexOutOfBounds)` -> If there is no element at `index`
+    pub fn remove_at(&mut self, index: usize) -> Result<T, ListError> {
+        if index >= self.len {
+            return Err(ListError::IndexOutOfBounds { index, len: self.len });
+        }
+
+        if index == 0 {
+            return Ok(self.pop_front().expect("index is in range"));
+        }
+
+        let prev = self.node_at(index - 1).expect("index is in range");
+        let removed = prev.borrow().next.clone().expect("ring is closed");
+        prev.borrow_mut().next = removed.borrow_mut().next.take();
+        if index == self.len - 1 {
+            self.tail = Some(prev);
+        }
+        self.len -= 1;
+
+        Ok(Self::into_data(removed))
+    }
+
+    /// Find a element in the list and return its index, counting from head
+    /// 
+    /// return:
+    /// * `(bool, usize)` -> `(true, index)` | `(false, 0)`
+    pub fn find(&self, data: &T) -> (bool, usize)
+    where
+        T: PartialEq
+    {
+        match self.iter().position(|value| value == data) {
+            Some(index) => (true, index),
+            None => (false, 0),
+        }
+    }
+
+    /// Move every element of `other` to the back of this list in O(1), leaving `other` empty
+    /// 
+    /// args:
+    /// * `other`: the list to move the elements from
+    pub fn append(&mut self, other: &mut Self) {
+        let (other_head, other_tail) = match (other.head.take(), other.tail.take()) {
+            (Some(head), Some(tail)) => (head, tail),
+            _ => return,
+        };
+        let other_len = std::mem::take(&mut other.len);
+
+        match (&self.head, &self.tail) {
+            (Some(head), Some(tail)) => {
+                tail.borrow_mut().next = Some(other_head);
+                other_tail.borrow_mut().next = Some(head.clone());
             }
+            _ => self.head = Some(other_head),
         }
 
-        self.head = Some(new_node);
+        self.tail = Some(other_tail);
+        self.len += other_len;
+    }
+
+    /// Move every element of `other` to the front of this list in O(1)
+    /// 
+    /// args:
+    /// * `other`: the list to put in front
+    pub fn prepend(&mut self, mut other: Self) {
+        other.append(self);
+        std::mem::swap(self, &mut other);
+    }
+
+    /// Split the ring in two at a specific index, closing both rings again
+    /// 
+    /// args:
+    /// * `at`: the index of the first element of the returned list
+    /// 
+    /// return:
+    /// * `Ok(CircularLinkedList<T>)` -> The elements from `at` onwards
+    /// * `Err(ListError::IndexOutOfBounds)` -> If `at` is greater than `len()`
+    pub fn split_off(&mut self, at: usize) -> Result<Self, ListError> {
+        if at > self.len {
+            return Err(ListError::IndexOutOfBounds { index: at, len: self.len });
+        }
+
+        if at == 0 {
+            return Ok(std::mem::take(self));
+        }
+
+        if at == self.len {
+            return Ok(Self::new());
+        }
+
+        let new_tail = self.node_at(at - 1).expect("at is in range");
+        let other_head = new_tail.borrow_mut().next.replace(self.head.clone().expect("list is not empty"));
+        let other_tail = self.tail.replace(new_tail).expect("list is not empty");
+        other_tail.borrow_mut().next = other_head.clone();
+
+        let len = self.len - at;
+        self.len = at;
+        Ok(CircularLinkedList {
+            head: other_head,
+            tail: Some(other_tail),
+            len
+        })
+    }
+
+    /// Move every element of `other` into this list at a specific index
+    /// 
+    /// After the splice the first element of `other` is at position `index`
+    /// 
+    /// return:
+    /// * `Err(ListError::IndexOutOfBounds)` -> If `index` is greater than `len()`
+    pub fn splice_at(&mut self, index: usize, mut other: Self) -> Result<(), ListError> {
+        let mut rest = self.split_off(index)?;
+        self.append(&mut other);
+        self.append(&mut rest);
+        Ok(())
+    }
+
+    /// Reverse the direction of the ring, the old tail becomes the new head
+    pub fn reverse(&mut self) {
+        if self.len < 2 {
+            return;
+        }
+
+        let mut prev = self.tail.clone();
+        let mut current = self.head.clone();
+        for _ in 0..self.len {
+            let node = current.expect("ring is closed");
+            let next = node.borrow_mut().next.take();
+            node.borrow_mut().next = prev;
+            prev = Some(node);
+            current = next;
+        }
+
+        std::mem::swap(&mut self.head, &mut self.tail);
+    }
+
+    /// Move the start of the ring `n` steps forward
+    /// 
+    /// The second element becomes the head after `rotate_left(1)`. Only the
+    /// `head`/`tail` pointers move, no node is relinked.
+    pub fn rotate_left(&mut self, n: usize) {
+        if self.len < 2 {
+            return;
+        }
+
+        let steps = n % self.len;
+        if steps == 0 {
+            return;
+        }
+
+        let new_tail = self.node_at(steps - 1).expect("steps is in range");
+        self.head = new_tail.borrow().next.clone();
+        self.tail = Some(new_tail);
     }
 
+    /// Move the start of the ring `n` steps backward
+    /// 
+    /// The last element becomes the head after `rotate_right(1)`
+    pub fn rotate_right(&mut self, n: usize) {
+        if self.len < 2 {
+            return;
+        }
+
+        self.rotate_left(self.len - n % self.len);
+    }
+
+    /// Get a cursor starting at head that can step around the ring forever
+    pub fn cursor(&self) -> Cursor<'_, T> {
+        Cursor {
+            current: self.head.as_deref(),
+            index: 0,
+            len: self.len
+        }
+    }
+
+    /// Iterate over the data going around the ring `k` times from head
+    pub fn take_cycles(&self, k: usize) -> Iter<'_, T> {
+        Iter {
+            next: self.head.as_deref(),
+            len: self.len.saturating_mul(k)
+        }
+    }
 
     /// Print all the items/data of the list
-    fn display(&self)
+    pub fn display(&self)
     where T: std::fmt::Display
     {
-        if let Some(head) = &self.head {
-            let mut current = head.clone();
-            loop {
-                print!("{} -> ", current.borrow().data);
-                let next = current.borrow().next.clone();
-                
-                if let Some(next_node) = next {
-                    if Rc::ptr_eq(&next_node, head) {
-                        break;
-                    }
-                    current = next_node;
-                }
-            }
-            println!("(back to start)");
-        } else {
-            println!("Empty list");
-        }
+        println!("{}", self);
+    }
+
+    /// Get the number of items in the list in O(1)
+    pub fn len(&self) -> usize {
+        self.len
     }
 
     /// Check if the list is empty or not
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    /// Remove every element from the list
+    /// 
+    /// Nodes are unlinked one at a time from the front, so the ring is
+    /// broken and every node is freed
+    pub fn clear(&mut self) {
+        while !self.is_empty() {
+            self.pop_front();
+        }
+    }
+
+    /// Check that the ring is closed, `tail` is its last node and `len` matches
     /// 
-    /// It doesn't return any type because it is use for instant check
-    fn is_empty(&self) {
-        if self.head.is_none() {
-            println!("Linked List is empty");
+    /// Walks the whole ring and panics on the first broken link. Only runs in
+    /// debug builds, in release builds it returns immediately.
+    pub fn check_links(&self) {
+        if !cfg!(debug_assertions) {
             return;
         }
+
+        let (head, tail) = match (&self.head, &self.tail) {
+            (Some(head), Some(tail)) => (head, tail),
+            (None, None) => {
+                assert_eq!(self.len, 0, "empty list has non-zero len");
+                return;
+            }
+            _ => panic!("only one of head/tail is set"),
+        };
+
+        let mut current = head.clone();
+        for index in 1..self.len {
+            let next = current.borrow().next.clone().expect("ring is closed");
+            assert!(!Rc::ptr_eq(&next, head), "ring closes after {} of {} nodes", index, self.len);
+            current = next;
+        }
+
+        assert!(Rc::ptr_eq(&current, tail), "last node is not the tail");
+        let next = tail.borrow().next.clone().expect("ring is closed");
+        assert!(Rc::ptr_eq(&next, head), "tail does not link back to head");
+    }
+
+    /// Iterate over references to the data, going once around the ring from head
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            next: self.head.as_deref(),
+            len: self.len
+        }
+    }
+
+    /// Iterate over mutable references to the data, going once around the ring from head
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            next: self.head.as_deref(),
+            len: self.len,
+            _marker: PhantomData
+        }
+    }
+
+    /// Link a new node in between `tail` and `head`
+    /// 
+    /// The caller decides which end it belongs to by making it the new head
+    /// or the new tail. An empty list gets a node that links to itself and is
+    /// both head and tail.
+    fn link_new(&mut self, data: T) -> Rc<RefCell<Node<T>>> {
+        let new_node = Rc::new(RefCell::new(Node {
+            data,
+            next: None
+        }));
+
+        match (&self.head, &self.tail) {
+            (Some(head), Some(tail)) => {
+                new_node.borrow_mut().next = Some(head.clone());
+                tail.borrow_mut().next = Some(new_node.clone());
+            }
+            _ => {
+                new_node.borrow_mut().next = Some(new_node.clone());
+                self.head = Some(new_node.clone());
+                self.tail = Some(new_node.clone());
+            }
+        }
+
+        self.len += 1;
+        new_node
+    }
+
+    /// Splice a new node in right after `node`
+    fn link_after(node: &Rc<RefCell<Node<T>>>, data: T) {
+        let next = node.borrow_mut().next.take();
+        let new_node = Rc::new(RefCell::new(Node { data, next }));
+        node.borrow_mut().next = Some(new_node);
+    }
+
+    /// Get the node at `index`, counting from head
+    fn node_at(&self, index: usize) -> Option<Rc<RefCell<Node<T>>>> {
+        if index >= self.len {
+            return None;
+        }
+
+        let mut current = self.head.clone()?;
+        for _ in 0..index {
+            let next = current.borrow().next.clone()?;
+            current = next;
+        }
+        Some(current)
+    }
+
+    /// Remove the only node of a single element list, breaking its self-link
+    fn pop_only(&mut self) -> Option<T> {
+        self.tail = None;
+        let node = self.head.take()?;
+        node.borrow_mut().next = None;
+        self.len = 0;
+
+        Some(Self::into_data(node))
+    }
+
+    /// Take the data out of a node that has been unlinked from the ring
+    fn into_data(node: Rc<RefCell<Node<T>>>) -> T {
+        match Rc::try_unwrap(node) {
+            Ok(node) => node.into_inner().data,
+            Err(_) => unreachable!("unlinked node is still referenced"),
+        }
+    }
+}
+
+// The iterators below hand out references into `RefCell`s without holding a
+// `Ref`/`RefMut` guard. This is sound because they borrow the list itself:
+// `iter` holds `&self` so nothing can mutate the nodes while it lives, and
+// `iter_mut` holds `&mut self` and yields each node's data exactly once. The
+// `len` counter is what stops them from going around the ring again.
+
+/// Borrowing iterator returned by [`CircularLinkedList::iter`]
+pub struct Iter<'a, T> {
+    next: Option<&'a RefCell<Node<T>>>,
+    len: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.next.map(|cell| {
+            // SAFETY: see the comment above `Iter`
+            let node = unsafe { &*cell.as_ptr() };
+            self.next = node.next.as_deref();
+            self.len -= 1;
+            &node.data
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+/// Read-only cursor returned by [`CircularLinkedList::cursor`]
+/// 
+/// Unlike [`Iter`] it never runs off the end, `move_next` on the last
+/// element wraps back to head. Used as an iterator it is endless for a
+/// non-empty list, so pair it with `take` or `take_while`.
+pub struct Cursor<'a, T> {
+    current: Option<&'a RefCell<Node<T>>>,
+    index: usize,
+    len: usize,
+}
+
+impl<'a, T> Cursor<'a, T> {
+    /// Get the element under the cursor, `None` if the list is empty
+    pub fn current(&self) -> Option<&'a T> {
+        // SAFETY: see the comment above `Iter`
+        self.current.map(|cell| unsafe { &(*cell.as_ptr()).data })
+    }
+
+    /// Get the element the cursor would move to next
+    pub fn peek_next(&self) -> Option<&'a T> {
+        // SAFETY: see the comment above `Iter`
+        self.next_cell().map(|cell| unsafe { &(*cell.as_ptr()).data })
+    }
+
+    /// Get the index of the element under the cursor, counting from head
+    pub fn index(&self) -> Option<usize> {
+        self.current.map(|_| self.index)
+    }
+
+    /// Step to the next element, wrapping around from the tail to head
+    pub fn move_next(&mut self) {
+        if self.current.is_some() {
+            self.current = self.next_cell();
+            self.index = (self.index + 1) % self.len;
+        }
+    }
+
+    fn next_cell(&self) -> Option<&'a RefCell<Node<T>>> {
+        // SAFETY: see the comment above `Iter`
+        self.current.and_then(|cell| unsafe { (*cell.as_ptr()).next.as_deref() })
+    }
+}
+
+impl<'a, T> Iterator for Cursor<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let data = self.current()?;
+        self.move_next();
+        Some(data)
+    }
+}
+
+/// Mutable iterator returned by [`CircularLinkedList::iter_mut`]
+pub struct IterMut<'a, T> {
+    next: Option<&'a RefCell<Node<T>>>,
+    len: usize,
+    _marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.next.map(|cell| {
+            let node = cell.as_ptr();
+            self.len -= 1;
+            // SAFETY: see the comment above `Iter`
+            unsafe {
+                self.next = (*node).next.as_deref();
+                &mut (*node).data
+            }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+/// Consuming iterator returned by [`CircularLinkedList::into_iter`]
+/// 
+/// The ring is broken once up front, after that the nodes are
+/// handed out as a plain chain.
+pub struct IntoIter<T> {
+    next: Option<Rc<RefCell<Node<T>>>>,
+    len: usize,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.next.take()?;
+        let node = Rc::try_unwrap(node)
+            .ok()
+            .expect("node is only owned by the chain")
+            .into_inner();
+
+        self.next = node.next;
+        self.len -= 1;
+        Some(node.data)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+// Whatever is left of the chain would otherwise be dropped recursively
+impl<T> Drop for IntoIter<T> {
+    fn drop(&mut self) {
+        let mut next = self.next.take();
+        while let Some(node) = next {
+            next = node.borrow_mut().next.take();
+        }
+    }
+}
+
+impl<T> IntoIterator for CircularLinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(mut self) -> Self::IntoIter {
+        // Break the ring so the tail no longer owns the head
+        if let Some(tail) = self.tail.take() {
+            tail.borrow_mut().next = None;
+        }
+
+        IntoIter {
+            next: self.head.take(),
+            len: self.len
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a CircularLinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut CircularLinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T> Index<usize> for CircularLinkedList<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        let len = self.len;
+        self.get(index)
+            .unwrap_or_else(|| panic!("{}", ListError::IndexOutOfBounds { index, len }))
     }
 }
 
-#[allow(dead_code)]
-pub fn run() {
-    let mut circular_ll = CircularLinkedList::new();
+impl<T> IndexMut<usize> for CircularLinkedList<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let len = self.len;
+        self.get_mut(index)
+            .unwrap_or_else(|| panic!("{}", ListError::IndexOutOfBounds { index, len }))
+    }
+}
 
-    circular_ll.push_front(&50);
-    circular_ll.push_front(&60);
+impl<T> Extend<T> for CircularLinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for data in iter {
+            self.push_back(data);
+        }
+    }
+}
 
-    circular_ll.display();
-}
\ No newline at end of file
+impl<T> FromIterator<T> for CircularLinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T: Clone> Clone for CircularLinkedList<T> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: PartialEq> PartialEq for CircularLinkedList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for CircularLinkedList<T> {}
+
+impl<T: Hash> Hash for CircularLinkedList<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        for data in self.iter() {
+            data.hash(state);
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for CircularLinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for CircularLinkedList<T> {
+    /// Renders the list once around the ring as `a -> b -> (back to start)`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "Empty list");
+        }
+
+        for data in self.iter() {
+            write!(f, "{} -> ", data)?;
+        }
+        write!(f, "(back to start)")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic::{self, AssertUnwindSafe};
+
+    use crate::linked_list::test_utils;
+    use super::*;
+
+    /// Check the ring and compare it with `expected`, starting from head
+    fn assert_ring(list: &CircularLinkedList<i32>, expected: &[i32]) {
+        list.check_links();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), expected);
+        assert_eq!(list.len(), expected.len());
+        assert_eq!(list.is_empty(), expected.is_empty());
+    }
+
+    #[test]
+    fn pushes_and_pops_keep_the_ring_closed() {
+        let mut list = CircularLinkedList::new();
+        assert_eq!(list.pop_back(), None);
+        assert_eq!(list.pop_front(), None);
+
+        list.push_back(2);
+        assert_ring(&list, &[2]);
+        list.push_front(1);
+        list.push_back(3);
+        list.push_back(4);
+        assert_ring(&list, &[1, 2, 3, 4]);
+
+        assert_eq!(list.pop_back(), Some(4));
+        assert_ring(&list, &[1, 2, 3]);
+        assert_eq!(list.pop_front(), Some(1));
+        assert_ring(&list, &[2, 3]);
+        assert_eq!(list.pop_back(), Some(3));
+        assert_ring(&list, &[2]);
+        assert_eq!(list.pop_back(), Some(2));
+        assert_ring(&list, &[]);
+
+        list.push_back(5);
+        list.push_back(6);
+        assert_ring(&list, &[5, 6]);
+    }
+
+    #[test]
+    fn insert_at_index_at_front_middle_and_back() {
+        let mut list = CircularLinkedList::new();
+        assert!(list.insert_at_index(0, 2).is_ok());
+        assert!(list.insert_at_index(0, 0).is_ok());
+        assert!(list.insert_at_index(2, 4).is_ok());
+        assert_ring(&list, &[0, 2, 4]);
+        assert!(list.insert_at_index(1, 1).is_ok());
+        assert!(list.insert_at_index(3, 3).is_ok());
+        assert_ring(&list, &[0, 1, 2, 3, 4]);
+
+        assert_eq!(
+            list.insert_at_index(6, 9),
+            Err(ListError::IndexOutOfBounds { index: 6, len: 5 })
+        );
+        assert_ring(&list, &[0, 1, 2, 3, 4]);
+        list.push_back(5);
+        assert_ring(&list, &[0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn insert_after_the_first_match() {
+        let mut list: CircularLinkedList<_> = [1, 2, 1].into_iter().collect();
+        assert!(list.insert_after(&1, 5).is_ok());
+        assert_ring(&list, &[1, 5, 2, 1]);
+
+        // After the tail the new element becomes the tail
+        let mut single: CircularLinkedList<_> = [3].into_iter().collect();
+        assert!(single.insert_after(&3, 4).is_ok());
+        assert_ring(&single, &[3, 4]);
+        single.push_back(5);
+        assert_ring(&single, &[3, 4, 5]);
+
+        assert_eq!(list.insert_after(&9, 0), Err(ListError::NotFound));
+        assert_ring(&list, &[1, 5, 2, 1]);
+        let mut empty = CircularLinkedList::new();
+        assert_eq!(empty.insert_after(&1, 0), Err(ListError::Empty));
+        assert_ring(&empty, &[]);
+    }
+
+    #[test]
+    fn delete_at_node_at_head_middle_and_tail() {
+        let mut list: CircularLinkedList<_> = [1, 2, 3, 2, 4].into_iter().collect();
+        assert_eq!(list.delete_at_node(&2), Ok(2));
+        assert_ring(&list, &[1, 3, 2, 4]);
+        assert_eq!(list.delete_at_node(&1), Ok(1));
+        assert_ring(&list, &[3, 2, 4]);
+        assert_eq!(list.delete_at_node(&4), Ok(4));
+        assert_ring(&list, &[3, 2]);
+        list.push_back(5);
+        assert_ring(&list, &[3, 2, 5]);
+
+        assert_eq!(list.delete_at_node(&9), Err(ListError::NotFound));
+        assert_ring(&list, &[3, 2, 5]);
+        for value in [2, 3, 5] {
+            assert_eq!(list.delete_at_node(&value), Ok(value));
+        }
+        assert_ring(&list, &[]);
+        assert_eq!(list.delete_at_node(&1), Err(ListError::Empty));
+    }
+
+    #[test]
+    fn remove_at_the_back_moves_the_tail() {
+        let mut list: CircularLinkedList<_> = (0..4).collect();
+        assert_eq!(list.remove_at(3), Ok(3));
+        assert_ring(&list, &[0, 1, 2]);
+        list.push_back(4);
+        assert_ring(&list, &[0, 1, 2, 4]);
+
+        assert_eq!(list.remove_at(1), Ok(1));
+        assert_eq!(list.remove_at(0), Ok(0));
+        assert_ring(&list, &[2, 4]);
+        assert_eq!(list.remove_at(2), Err(ListError::IndexOutOfBounds { index: 2, len: 2 }));
+        assert_eq!(list.remove_at(1), Ok(4));
+        assert_eq!(list.remove_at(0), Ok(2));
+        assert_ring(&list, &[]);
+    }
+
+    #[test]
+    fn find_counts_from_head() {
+        let list: CircularLinkedList<_> = [4, 5, 4].into_iter().collect();
+        assert_eq!(list.find(&4), (true, 0));
+        assert_eq!(list.find(&5), (true, 1));
+        assert_eq!(list.find(&6), (false, 0));
+        assert_eq!(CircularLinkedList::new().find(&4), (false, 0));
+    }
+
+    #[test]
+    fn reverse_turns_the_ring_around() {
+        for len in 0..5 {
+            let mut list: CircularLinkedList<_> = (0..len).collect();
+            list.reverse();
+            let expected: Vec<_> = (0..len).rev().collect();
+            assert_ring(&list, &expected);
+
+            list.push_back(9);
+            list.push_front(-1);
+            assert_eq!(list.len(), len as usize + 2);
+            list.check_links();
+        }
+    }
+
+    #[test]
+    fn rotations_match_a_rotated_vec() {
+        for len in 0..5 {
+            for n in [0, 1, 2, len, len + 1, 3 * len + 2] {
+                let expected: Vec<_> = (0..len as i32).collect();
+
+                let mut list: CircularLinkedList<_> = expected.iter().copied().collect();
+                list.rotate_left(n);
+                let mut rotated = expected.clone();
+                rotated.rotate_left(n.checked_rem(len).unwrap_or(0));
+                assert_ring(&list, &rotated);
+
+                let mut list: CircularLinkedList<_> = expected.iter().copied().collect();
+                list.rotate_right(n);
+                let mut rotated = expected.clone();
+                rotated.rotate_right(n.checked_rem(len).unwrap_or(0));
+                assert_ring(&list, &rotated);
+            }
+        }
+
+        // Only head and tail move, pushes still go to the right ends
+        let mut list: CircularLinkedList<_> = (0..4).collect();
+        list.rotate_left(1);
+        list.push_back(9);
+        list.push_front(8);
+        assert_ring(&list, &[8, 1, 2, 3, 0, 9]);
+        list.rotate_right(7);
+        assert_ring(&list, &[9, 8, 1, 2, 3, 0]);
+    }
+
+    #[test]
+    fn cursor_wraps_around_the_ring() {
+        let list: CircularLinkedList<_> = (0..3).collect();
+        let mut cursor = list.cursor();
+        assert_eq!(cursor.current(), Some(&0));
+        assert_eq!(cursor.index(), Some(0));
+        assert_eq!(cursor.peek_next(), Some(&1));
+
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&2));
+        assert_eq!(cursor.peek_next(), Some(&0));
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&0));
+        assert_eq!(cursor.index(), Some(0));
+
+        // Seven more laps and one step
+        for _ in 0..22 {
+            cursor.move_next();
+        }
+        assert_eq!(cursor.current(), Some(&1));
+        assert_eq!(cursor.index(), Some(1));
+
+        let round_robin: Vec<_> = list.cursor().take(8).copied().collect();
+        assert_eq!(round_robin, [0, 1, 2, 0, 1, 2, 0, 1]);
+
+        let single: CircularLinkedList<_> = [5].into_iter().collect();
+        let mut cursor = single.cursor();
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&5));
+        assert_eq!(cursor.index(), Some(0));
+        assert_eq!(cursor.peek_next(), Some(&5));
+    }
+
+    #[test]
+    fn cursor_on_an_empty_list_stays_empty() {
+        let list: CircularLinkedList<i32> = CircularLinkedList::new();
+        let mut cursor = list.cursor();
+        cursor.move_next();
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.peek_next(), None);
+        assert_eq!(cursor.index(), None);
+        assert_eq!(cursor.next(), None);
+    }
+
+    #[test]
+    fn take_cycles_goes_around_k_times() {
+        let list: CircularLinkedList<_> = (0..3).collect();
+        assert_eq!(list.take_cycles(0).count(), 0);
+        assert_eq!(list.take_cycles(1).copied().collect::<Vec<_>>(), [0, 1, 2]);
+
+        let cycles = list.take_cycles(3);
+        assert_eq!(cycles.len(), 9);
+        assert_eq!(cycles.copied().collect::<Vec<_>>(), [0, 1, 2, 0, 1, 2, 0, 1, 2]);
+
+        let empty: CircularLinkedList<i32> = CircularLinkedList::new();
+        assert_eq!(empty.take_cycles(5).len(), 0);
+        assert_eq!(empty.take_cycles(5).next(), None);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "check_links only runs in debug builds")]
+    fn check_links_catches_a_stale_tail() {
+        let mut list: CircularLinkedList<_> = (0..3).collect();
+        let tail = list.tail.replace(list.head.clone().expect("list is not empty"));
+        let result = panic::catch_unwind(AssertUnwindSafe(|| list.check_links()));
+        // Put the real tail back so the list can still be dropped
+        list.tail = tail;
+
+        let message = result.expect_err("check_links should panic").downcast::<&str>();
+        assert_eq!(message.ok().as_deref(), Some(&"last node is not the tail"));
+    }
+
+    test_utils::leak_tests!(CircularLinkedList, split_off, remove_at);
+
+    #[test]
+    #[cfg_attr(miri, ignore = "millions of nodes are too slow under Miri")]
+    fn long_lists_are_freed_without_recursing() {
+        // A recursive drop of this many nodes overflows the test thread's stack
+        const LEN: usize = 3_000_000;
+
+        drop((0..LEN).collect::<CircularLinkedList<_>>());
+
+        let mut iter = (0..LEN).collect::<CircularLinkedList<_>>().into_iter();
+        assert_eq!(iter.nth(LEN / 2), Some(LEN / 2));
+        assert_eq!(iter.len(), LEN / 2 - 1);
+        drop(iter);
+    }
+
+    #[test]
+    fn get_set_and_index_mut_stop_at_len() {
+        let mut list: CircularLinkedList<_> = (1..=4).collect();
+        assert_eq!(list.get(0), Some(&1));
+        assert_eq!(list.get(3), Some(&4));
+        // The ring does not wrap around for indices past the end
+        assert_eq!(list.get(4), None);
+
+        assert_eq!(list.set(0, 10), Ok(1));
+        assert_eq!(list.set(3, 40), Ok(4));
+        assert_eq!(list.set(4, 50), Err(ListError::IndexOutOfBounds { index: 4, len: 4 }));
+        *list.get_mut(1).unwrap() += 18;
+        assert_eq!(list.get_mut(4), None);
+        list[2] = 30;
+
+        assert_ring(&list, &[10, 20, 30, 40]);
+        assert_eq!((list[0], list[3]), (10, 40));
+
+        let mut empty = CircularLinkedList::<i32>::new();
+        assert_eq!(empty.get_mut(0), None);
+        assert_eq!(empty.set(0, 1), Err(ListError::IndexOutOfBounds { index: 0, len: 0 }));
+    }
+
+    #[test]
+    #[should_panic(expected = "index 3 out of range for list of length 3")]
+    fn index_past_the_end_panics() {
+        let list: CircularLinkedList<_> = (1..=3).collect();
+        let _ = list[3];
+    }
+
+    #[test]
+    #[should_panic(expected = "index 0 out of range for list of length 0")]
+    fn index_mut_on_an_empty_list_panics() {
+        let mut list = CircularLinkedList::new();
+        list[0] = 1;
+    }
+
+    #[test]
+    fn std_traits() {
+        test_utils::check_std_traits::<CircularLinkedList<i32>>("1 -> 2 -> 3 -> (back to start)", "Empty list");
+    }
+}
@@ -1,396 +1,1981 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{cmp::Ordering, fmt, hash::{Hash, Hasher}, marker::PhantomData, mem, ops::{Index, IndexMut}, ptr::NonNull};
 
-#[derive(Debug, Clone)]
-struct Node<'a, T> {
-    data: &'a T,
-    next: Option<Rc<RefCell<Node<'a, T>>>>,
-    prev: Option<Rc<RefCell<Node<'a, T>>>>,
+use crate::ListError;
+
+struct Node<T> {
+    data: T,
+    next: Option<NonNull<Node<T>>>,
+    prev: Option<NonNull<Node<T>>>,
+}
+
+// Every node is allocated with `Box` and owned by exactly one list. Its
+// neighbours only hold raw pointers to it, so there is no reference counting
+// or borrow flag on the way from one node to the next. The list frees the
+// nodes itself, in `into_data` and `Drop`.
+//
+// All `unsafe` blocks below rely on the same invariants:
+// * `head`, `tail` and every `next`/`prev` link point to live nodes of this list
+// * `len` is the number of nodes reachable from `head`
+// * a node is only turned back into a `Box` after it has been unlinked
+pub struct DoublyLinkedList<T> {
+    head: Option<NonNull<Node<T>>>,
+    tail: Option<NonNull<Node<T>>>,
+    len: usize,
+    // Tells the drop checker that the list owns `Node<T>`s
+    _marker: PhantomData<Box<Node<T>>>,
+}
+
+// SAFETY: the list owns its nodes like a `Box` would, sending or sharing it
+// sends or shares the `T`s and nothing else
+unsafe impl<T: Send> Send for DoublyLinkedList<T> {}
+unsafe impl<T: Sync> Sync for DoublyLinkedList<T> {}
+
+impl<T> Default for DoublyLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
-struct DoublyLinkedList<'a, T> {
-    head: Option<Rc<RefCell<Node<'a, T>>>>,
-    tail: Option<Rc<RefCell<Node<'a, T>>>>,
+// The nodes are owned through raw pointers, so they have to be freed by hand
+impl<T> Drop for DoublyLinkedList<T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
 }
 
-#[allow(dead_code)]
-impl<'a, T: std::fmt::Debug + PartialEq + Copy + Clone + std::fmt::Display> DoublyLinkedList<'a, T> {
+impl<T> DoublyLinkedList<T> {
     /// Create a new doubly linked list
     /// 
     /// Will create a new doubly linked list with no nodes
-    fn new() -> Self {
-        DoublyLinkedList { head: None, tail: None }
+    pub fn new() -> Self {
+        DoublyLinkedList { head: None, tail: None, len: 0, _marker: PhantomData }
     }
 
-    /// Push a new node to the back of the list
+    /// Push a new node to the front of the list
+    /// 
+    /// Will create a new node with the given data and make it the new head,
+    /// linking the old head back to it
     /// 
-    /// Will create a new node with the given data and add it to the end of the list
     /// args:
     /// * `data`: The data to be added to the list
-    fn push_front(&mut self, data: &'a T) {
-        let new_node = Rc::new(RefCell::new(Node {
-            data,
-            next: None,
-            prev: None
-        }));
-    
-        match self.head.take() {
-            Some(old_head) => {
-                old_head.borrow_mut().prev = Some(new_node.clone());
-                let new_head = Rc::new(RefCell::new(Node {
-                    data,
-                    next: Some(old_head),
-                    prev: None
-                }));
-                self.head = Some(new_head);
-            }
-            None => {
+    pub fn push_front(&mut self, data: T) {
+        let new_node = Self::new_node(data);
+
+        // SAFETY: `new_node` was just allocated and the head is a live node
+        unsafe {
+            (*new_node.as_ptr()).next = self.head;
+            match self.head {
+                Some(old_head) => (*old_head.as_ptr()).prev = Some(new_node),
                 // List is empty
-                self.head = Some(new_node.clone());
-                self.tail = Some(new_node);
+                None => self.tail = Some(new_node),
             }
         }
+
+        self.head = Some(new_node);
+        self.len += 1;
     }
 
     /// Push a new node to the back of the list
     /// 
-    /// Will create a new node with the given data and add it to the end of the list
+    /// Will create a new node with the given data and add it to the end of the list,
+    /// an empty list gets the node as both head and tail
     /// 
     /// args:
     /// * `data`: The data to be added to the list
-    fn push_back(&mut self, data: &'a T) {
-       if self.head.is_none() {
-            println!("List is empty");
-            return;
+    pub fn push_back(&mut self, data: T) {
+        let new_node = Self::new_node(data);
+
+        // SAFETY: `new_node` was just allocated and the tail is a live node
+        unsafe {
+            (*new_node.as_ptr()).prev = self.tail;
+            match self.tail {
+                Some(old_tail) => (*old_tail.as_ptr()).next = Some(new_node),
+                // List is empty
+                None => self.head = Some(new_node),
+            }
         }
-        if let Some(tail) = &self.tail {
-            let new_node = Rc::new(RefCell::new(Node {
-                data, // Placeholder data
-                next: None,
-                prev: Some(tail.clone())
-            }));
-            
-            tail.borrow_mut().next = Some(new_node.clone());
-            self.tail = Some(new_node);
+
+        self.tail = Some(new_node);
+        self.len += 1;
+    }
+
+    /// Insert a node/data after the first node holding a certain data.
+    /// 
+    /// args:
+    /// * `target`: The data of the node to insert after
+    /// 
+    /// * `data`: The node/data to be inserted
+    /// 
+    /// return:
+    /// * `Err(ListError::Empty)` | `Err(ListError::NotFound)`
+    pub fn push_after_node(&mut self, target: &T, data: T) -> Result<(), ListError>
+    where
+        T: PartialEq
+    {
+        if self.head.is_none() {
+            return Err(ListError::Empty);
+        }
+
+        let mut current = self.head;
+
+        while let Some(node) = current {
+            // SAFETY: `node` is a live node of this list
+            unsafe {
+                if (*node.as_ptr()).data == *target {
+                    self.link_after(node, data);
+                    return Ok(());
+                }
+
+                current = (*node.as_ptr()).next;
+            }
         }
+
+        Err(ListError::NotFound)
     }
-    
-    /// Insert a node/data after a certain node/data.
+
+    /// Insert a copy of a node/data after every node holding a certain data.
+    /// 
+    /// Newly inserted nodes are never matched themselves, so this terminates
+    /// even when `data == target`.
     /// 
     /// args:
-    /// * `_node`: The node required to push for `data`
-    ///
+    /// * `target`: The data of the node to insert after
+    /// 
     /// * `data`: The node/data to be inserted
-    fn push_after_node(&mut self, _node: &'a T, data: &'a T) {
+    /// 
+    /// return:
+    /// * `Ok(usize)` -> The number of nodes inserted
+    /// * `Err(ListError::Empty)` | `Err(ListError::NotFound)`
+    pub fn push_after_all_nodes(&mut self, target: &T, data: T) -> Result<usize, ListError>
+    where
+        T: PartialEq + Clone
+    {
         if self.head.is_none() {
-            println!("List is empty");
-            return;
+            return Err(ListError::Empty);
+        }
+
+        let mut current = self.head;
+        let mut inserted = 0;
+
+        while let Some(node) = current {
+            // SAFETY: `node` and the newly linked node are live nodes of this list
+            unsafe {
+                if (*node.as_ptr()).data == *target {
+                    // Skip over the node we just inserted
+                    let new_node = self.link_after(node, data.clone());
+                    inserted += 1;
+                    current = (*new_node.as_ptr()).next;
+                } else {
+                    current = (*node.as_ptr()).next;
+                }
+            }
+        }
+
+        if inserted == 0 {
+            return Err(ListError::NotFound);
         }
+        Ok(inserted)
+    }
 
-        let mut current = self.head.clone();
-
-        while let Some(ref node) = current.clone() {
-            // println!("Node: {}", &node.borrow_mut().data);
-            if node.borrow_mut().data == _node {
-                let new_node = Rc::new(RefCell::new(Node {
-                    data,
-                    next: node.borrow().next.clone(),
-                    prev: node.borrow().prev.clone(), 
-                }));
-                
-                node.borrow_mut().next = Some(new_node);
-            }            
-            
-            current = node.borrow_mut().next.clone();
-        } 
-    }
-    
     /// Insert a node/data with that index
     /// 
+    /// After the insert the new node is at position `index`, so `0` pushes to
+    /// the front and `len()` pushes to the back.
+    /// 
     /// args:
     /// * `index`: Index where the node/data will be inserted
     /// 
     /// * `data`: The node/data to push inserted to the `index`
-    fn insert_at_index(&mut self, index: u32, data: &'a T) {
-        self.is_empty();
-        
-        if index > self.get_length() - 1 {
-            println!("Index out of range");
-            return;
+    /// 
+    /// return:
+    /// * `Err(ListError::IndexOutOfBounds)` -> If `index` is greater than `len()`
+    pub fn insert_at_index(&mut self, index: usize, data: T) -> Result<(), ListError> {
+        if index > self.len {
+            return Err(ListError::IndexOutOfBounds { index, len: self.len });
         }
 
-        let mut current = self.head.clone();
-        let mut index_counter = 0;
+        if index == 0 {
+            self.push_front(data);
+            return Ok(());
+        }
 
-        while let Some(node) = current {
-            if index_counter == index {
-                let new_node = Rc::new(RefCell::new(Node {
-                    data,
-                    next: node.borrow().next.clone(),
-                    prev: node.borrow().prev.clone(), 
-                }));
-                
-                node.borrow_mut().next = Some(new_node);
-            }
+        // The node that will precede the new one
+        let node = self.node_at(index - 1).expect("index is in range");
+        // SAFETY: `node_at` only returns live nodes of this list
+        unsafe {
+            self.link_after(node, data);
+        }
+        Ok(())
+    }
 
-            current = node.borrow().next.clone();
-            index_counter += 1;
-        } 
+    /// Allocate a node that is not linked to anything yet
+    fn new_node(data: T) -> NonNull<Node<T>> {
+        NonNull::from(Box::leak(Box::new(Node {
+            data,
+            next: None,
+            prev: None
+        })))
     }
-    
-    /// Delete the front node of the list
-    fn delete_front(&mut self) {
-        self.is_empty();
-        
-        if self.get_length() == 1 {
-            self.head = None;
-            self.tail = None;
+
+    /// Splice a new node in right after `node`, fixing up the successor's
+    /// `prev` link and moving the tail if `node` was the last node
+    /// 
+    /// Safety: `node` must be a live node of this list
+    /// 
+    /// return:
+    /// * `NonNull<Node<T>>` -> The newly inserted node
+    unsafe fn link_after(&mut self, node: NonNull<Node<T>>, data: T) -> NonNull<Node<T>> {
+        let new_node = Self::new_node(data);
+
+        // SAFETY: guaranteed by the caller, the successor is live as well
+        unsafe {
+            let next = (*node.as_ptr()).next;
+            (*new_node.as_ptr()).prev = Some(node);
+            (*new_node.as_ptr()).next = next;
+
+            match next {
+                Some(next_node) => (*next_node.as_ptr()).prev = Some(new_node),
+                None => self.tail = Some(new_node),
+            }
+
+            (*node.as_ptr()).next = Some(new_node);
         }
 
-        self.head = self.head.clone().unwrap().borrow().next.clone();
+        self.len += 1;
+        new_node
     }
-    
-    /// Delete the back/end node of the list
-    fn delete_back(&mut self) {
-        self.is_empty();
 
-        if self.get_length() == 1 {
-            self.head = None;
-            self.tail = None; 
-        }
+    /// Remove the front node of the list
+    /// 
+    /// return:
+    /// * `Option<T>` -> The data of the removed node, `None` if the list is empty
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.head.map(|old_head| {
+            // SAFETY: the head is a live node, after unlinking nothing points to it
+            unsafe {
+                self.unlink_node(old_head);
+                Self::into_data(old_head)
+            }
+        })
+    }
 
-        if let Some(tail_node) = &self.tail.clone() {
-            if let Some(prev_node) = &tail_node.borrow().prev {
-                prev_node.borrow_mut().next = None;
-                self.tail = Some(Rc::clone(prev_node));
+    /// Remove the back/end node of the list
+    /// 
+    /// return:
+    /// * `Option<T>` -> The data of the removed node, `None` if the list is empty
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.tail.map(|old_tail| {
+            // SAFETY: the tail is a live node, after unlinking nothing points to it
+            unsafe {
+                self.unlink_node(old_tail);
+                Self::into_data(old_tail)
             }
-        }
+        })
     }
-    
+
     /// Delete a specific node from the list
     /// 
     /// args:
     /// * `data`: The node/data to be deleted if found in the list
-    fn delete_at_node(&mut self, data: &'a T) {
-        self.is_empty();
+    /// 
+    /// return:
+    /// * `Ok(T)` -> The data of the removed node
+    /// * `Err(ListError::Empty)` | `Err(ListError::NotFound)`
+    pub fn delete_at_node(&mut self, data: &T) -> Result<T, ListError>
+    where
+        T: PartialEq
+    {
+        let (head, tail) = match (self.head, self.tail) {
+            (Some(head), Some(tail)) => (head, tail),
+            _ => return Err(ListError::Empty),
+        };
 
-        if self.head.as_ref().unwrap().borrow().data == data {
-            self.delete_front();
-            return;
+        // SAFETY: every node reached through the links is a live node of this
+        // list, and a node is only freed right after it has been unlinked
+        unsafe {
+            if (*head.as_ptr()).data == *data {
+                return self.pop_front().ok_or(ListError::NotFound);
+            }
+
+            if (*tail.as_ptr()).data == *data {
+                return self.pop_back().ok_or(ListError::NotFound);
+            }
+
+            let mut current = (*head.as_ptr()).next;
+
+            while let Some(node) = current {
+                if (*node.as_ptr()).data == *data {
+                    self.unlink_node(node);
+                    return Ok(Self::into_data(node));
+                }
+
+                // Move to the next node
+                current = (*node.as_ptr()).next;
+            }
         }
-        
-        if self.tail.as_ref().unwrap().borrow().data == data {
-            self.delete_back();
-            return;
+
+        Err(ListError::NotFound)
+    }
+
+    /// Finding a node/data in a list and return the index of the node from the list
+    /// 
+    /// return:
+    /// * `(bool, usize)` -> `(true, index)` | `(false, 0)`
+    pub fn find(&self, data: &T) -> (bool, usize)
+    where
+        T: PartialEq
+    {
+        match self.iter().position(|value| value == data) {
+            Some(index) => (true, index),
+            None => (false, 0),
         }
+    }
 
-        let mut current = self.head.clone();
-        
-        while let Some(current_node) = current {
-            // Get the next node outside of a borrow scope
-            let next = {
-                let current_ref = current_node.borrow();
-                match &current_ref.next {
-                    Some(next_node) => {
-                        // Check if this is the node to delete
-                        if next_node.borrow().data == data {
-                            // Found the target node
-                            Some(next_node.clone())
-                        } else {
-                            // Not the target, continue searching
-                            None
-                        }
-                    },
-                    None => None
-                }
-            };
-            
-            // If we found the node to delete
-            if let Some(node_to_delete) = next {
-                // Get references to relevant nodes
-                let next_next = node_to_delete.borrow().next.clone();
-                
-                // Update pointers - done in separate scopes to avoid multiple borrows
-                {
-                    current_node.borrow_mut().next = next_next.clone();
+    /// Get a reference to the data at a specific index
+    /// 
+    /// Walks from the head or the tail, whichever is closer to `index`
+    /// 
+    /// return:
+    /// * `Option<&T>` -> `None` if `index` is out of range
+    pub fn get(&self, index: usize) -> Option<&T> {
+        // SAFETY: `node_at` only returns live nodes, `&self` keeps them alive
+        self.node_at(index).map(|node| unsafe { &(*node.as_ptr()).data })
+    }
+
+    /// Get a mutable reference to the data at a specific index
+    /// 
+    /// Walks from the head or the tail, whichever is closer to `index`
+    /// 
+    /// return:
+    /// * `Option<&mut T>` -> `None` if `index` is out of range
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        // SAFETY: `node_at` only returns live nodes, `&mut self` makes the
+        // reference unique
+        self.node_at(index).map(|node| unsafe { &mut (*node.as_ptr()).data })
+    }
+
+    /// Overwrite the data at a specific index
+    /// 
+    /// return:
+    /// * `Ok(T)` -> The data that was replaced
+    /// * `Err(ListError::IndexOutOfBounds)` -> If there is no node at `index`
+    pub fn set(&mut self, index: usize, data: T) -> Result<T, ListError> {
+        let len = self.len;
+        self.get_mut(index)
+            .map(|value| mem::replace(value, data))
+            .ok_or(ListError::IndexOutOfBounds { index, len })
+    }
+
+    /// Remove the node at a specific index
+    /// 
+    /// Walks from the head or the tail, whichever is closer to `index`
+    /// 
+    /// return:
+    /// * `Ok(T)` -> The data of the removed node
+    /// * `Err(ListError::IndexOutOfBounds)` -> If there is no node at `index`
+    pub fn remove_at(&mut self, index: usize) -> Result<T, ListError> {
+        let node = self.node_at(index).ok_or(ListError::IndexOutOfBounds { index, len: self.len })?;
+
+        // SAFETY: `node_at` only returns live nodes, after unlinking nothing points to it
+        unsafe {
+            self.unlink_node(node);
+            Ok(Self::into_data(node))
+        }
+    }
+
+    /// Get the node at `index`, walking from whichever end is closer
+    fn node_at(&self, index: usize) -> Option<NonNull<Node<T>>> {
+        if index >= self.len {
+            return None;
+        }
+
+        // SAFETY: `index < len`, so every link followed points to a live node
+        unsafe {
+            if index < self.len / 2 {
+                let mut current = self.head?;
+                for _ in 0..index {
+                    current = (*current.as_ptr()).next?;
                 }
-                
-                if let Some(next_next_ref) = next_next {
-                    next_next_ref.borrow_mut().prev = Some(current_node.clone());
+                Some(current)
+            } else {
+                let mut current = self.tail?;
+                for _ in index..self.len - 1 {
+                    current = (*current.as_ptr()).prev?;
                 }
-                
-                return; // Node deleted
+                Some(current)
             }
-            
-            // Move to the next node
-            let next = current_node.borrow().next.clone();
-            current = next;
         }
-        
-        println!("Node with data {} not found in the list", &data);
     }
 
-    /// Finding a node/data in a list and return the index of the node from the list
+    /// Move every node of `other` to the back of this list in O(1), leaving `other` empty
     /// 
-    /// return: 
-    /// * `(bool, u32)` -> `(true, index)` | `(false, 0)`
-    fn find(&self, data: &'a T) -> (bool, u32) {
-        let mut current = self.head.clone();
-        let mut index_counter: u32 = 0;
+    /// args:
+    /// * `other`: The list to move the nodes from
+    pub fn append(&mut self, other: &mut Self) {
+        let tail = match self.tail {
+            Some(tail) => tail,
+            None => return mem::swap(self, other),
+        };
 
-        while let Some(node) = current {
-            if node.borrow().data == data {
-                return (true, index_counter);
+        if let Some(other_head) = other.head.take() {
+            // SAFETY: both are live nodes, the nodes of `other` now belong to `self`
+            unsafe {
+                (*tail.as_ptr()).next = Some(other_head);
+                (*other_head.as_ptr()).prev = Some(tail);
             }
-            index_counter += 1;
-            current = node.borrow().next.clone();
-        } 
-        return (false, 0);
+
+            self.tail = other.tail.take();
+            self.len += mem::take(&mut other.len);
+        }
     }
-    
-    /// Get the length (total no. of items in the list)
+
+    /// Move every node of `other` to the front of this list in O(1)
     /// 
-    /// return: 
-    /// * `u32` -> The total number of items in the list
-    fn get_length(&self) -> u32 {
-        let mut current = self.head.clone();
-        let mut counter: u32 = 0;
+    /// args:
+    /// * `other`: The list to put in front
+    pub fn prepend(&mut self, mut other: Self) {
+        other.append(self);
+        mem::swap(self, &mut other);
+    }
 
-        while let Some(node) = current {
-            counter += 1;
-            current = node.borrow().next.clone();
+    /// Split the list in two at a specific index
+    /// 
+    /// Walks from the head or the tail, whichever is closer to `at`
+    /// 
+    /// args:
+    /// * `at`: The index of the first node of the returned list
+    /// 
+    /// return:
+    /// * `Ok(DoublyLinkedList<T>)` -> The nodes from `at` onwards
+    /// * `Err(ListError::IndexOutOfBounds)` -> If `at` is greater than `len()`
+    pub fn split_off(&mut self, at: usize) -> Result<Self, ListError> {
+        if at > self.len {
+            return Err(ListError::IndexOutOfBounds { index: at, len: self.len });
         }
-        
-        return counter;
-    }  
 
-    /// Display the list
-    fn display(&self) {
-        let mut current = self.head.clone();
-        while let Some(node) = current {
-            print!("{:?} -> ", node.borrow().data);
-            current = node.borrow().next.clone();
+        if at == 0 {
+            return Ok(mem::take(self));
         }
-        println!();
+
+        let node = self.node_at(at - 1).expect("at is in range");
+        // SAFETY: `node` and its successor are live nodes of this list
+        let head = unsafe {
+            match (*node.as_ptr()).next.take() {
+                Some(head) => {
+                    (*head.as_ptr()).prev = None;
+                    head
+                }
+                None => return Ok(Self::new()),
+            }
+        };
+
+        let len = self.len - at;
+        self.len = at;
+        Ok(DoublyLinkedList {
+            head: Some(head),
+            tail: self.tail.replace(node),
+            len,
+            _marker: PhantomData
+        })
+    }
+
+    /// Move every node of `other` into this list at a specific index
+    /// 
+    /// After the splice the first node of `other` is at position `index`.
+    /// Only the walk to `index` costs anything, the relinking is O(1).
+    /// 
+    /// return:
+    /// * `Err(ListError::IndexOutOfBounds)` -> If `index` is greater than `len()`
+    pub fn splice_at(&mut self, index: usize, mut other: Self) -> Result<(), ListError> {
+        let mut rest = self.split_off(index)?;
+        self.append(&mut other);
+        self.append(&mut rest);
+        Ok(())
+    }
+
+    /// Display the list
+    pub fn display(&self)
+    where
+        T: std::fmt::Display
+    {
+        println!("{}", self);
     }
-    
+
     /// Get the value of the tail node
-    fn get_tail_value(&self) {
-        if let Some(tail) = &self.tail {
-            println!("Tail value: {:?}", tail.borrow().data);
-        } else {
-            println!("List is empty");
+    /// 
+    /// return:
+    /// * `Option<&T>` -> The tail data, `None` if the list is empty
+    pub fn get_tail_value(&self) -> Option<&T> {
+        // SAFETY: the tail is a live node, `&self` keeps it alive
+        self.tail.map(|tail| unsafe { &(*tail.as_ptr()).data })
+    }
+
+    /// Find the index of the first node whose data matches a predicate
+    /// 
+    /// return:
+    /// * `Option<usize>` -> The index of the first match, `None` if nothing matches
+    pub fn position<F>(&self, predicate: F) -> Option<usize>
+    where
+        F: FnMut(&T) -> bool
+    {
+        self.iter().position(predicate)
+    }
+
+    /// Find the first data that matches a predicate
+    /// 
+    /// return:
+    /// * `Option<&T>` -> The first match, `None` if nothing matches
+    pub fn find_by<F>(&self, mut predicate: F) -> Option<&T>
+    where
+        F: FnMut(&T) -> bool
+    {
+        self.iter().find(|value| predicate(value))
+    }
+
+    /// Check if any node holds a specific data
+    pub fn contains(&self, data: &T) -> bool
+    where
+        T: PartialEq
+    {
+        self.iter().any(|value| value == data)
+    }
+
+    /// Keep only the nodes whose data matches a predicate, in a single pass
+    /// 
+    /// args:
+    /// * `keep`: Returns false for the nodes to remove
+    pub fn retain<F>(&mut self, mut keep: F)
+    where
+        F: FnMut(&T) -> bool
+    {
+        let mut cursor = self.cursor_front_mut();
+        while let Some(data) = cursor.current() {
+            if keep(data) {
+                cursor.move_next();
+            } else {
+                cursor.remove_current();
+            }
         }
     }
 
-    
-    /// check if list is empty without any return type like bool
+    /// Remove the first node holding a specific data
     /// 
-    /// Will be used for fast checking
-    fn is_empty(&self) {
-        if self.head.is_none() {
-            println!("List is empty");
+    /// return:
+    /// * `Option<T>` -> The removed data, `None` if no node holds `data`
+    pub fn remove_first(&mut self, data: &T) -> Option<T>
+    where
+        T: PartialEq
+    {
+        let index = self.iter().position(|value| value == data)?;
+        self.remove_at(index).ok()
+    }
+
+    /// Remove every node holding a specific data
+    /// 
+    /// return:
+    /// * `usize` -> The number of nodes removed
+    pub fn remove_all(&mut self, data: &T) -> usize
+    where
+        T: PartialEq
+    {
+        let len = self.len;
+        self.retain(|value| value != data);
+        len - self.len
+    }
+
+    /// Get the number of nodes in the list in O(1)
+    /// 
+    /// return:
+    /// * `usize` -> The total number of items in the list
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check if the list is empty
+    /// 
+    /// return:
+    /// * `bool` -> `true` if the list has no nodes
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    /// Remove every node from the list
+    /// 
+    /// Nodes are unlinked and freed one at a time from the front
+    pub fn clear(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+
+    /// Check that head/tail/prev/next links and `len` are consistent
+    /// 
+    /// Walks the whole list and panics on the first broken link. Only runs in
+    /// debug builds, in release builds it returns immediately.
+    pub fn check_links(&self) {
+        if !cfg!(debug_assertions) {
             return;
         }
+
+        let (head, tail) = match (self.head, self.tail) {
+            (Some(head), Some(tail)) => (head, tail),
+            (None, None) => {
+                assert_eq!(self.len, 0, "empty list has non-zero len");
+                return;
+            }
+            _ => panic!("only one of head/tail is set"),
+        };
+
+        // SAFETY: the walk stops at `len` nodes, which are live if `len` is right
+        unsafe {
+            assert!((*head.as_ptr()).prev.is_none(), "head has a prev node");
+            assert!((*tail.as_ptr()).next.is_none(), "tail has a next node");
+
+            let mut current = head;
+            let mut counter = 1;
+            while let Some(next_node) = (*current.as_ptr()).next {
+                assert!(counter < self.len, "list has more nodes than len");
+                assert!(
+                    (*next_node.as_ptr()).prev == Some(current),
+                    "node {} does not link back to node {}", counter, counter - 1
+                );
+                current = next_node;
+                counter += 1;
+            }
+
+            assert!(current == tail, "last node is not the tail");
+            assert_eq!(counter, self.len, "len does not match the number of nodes");
+        }
+    }
+
+    /// Unlink `node` from its neighbours, moving head/tail if it was at either end
+    /// 
+    /// `node` keeps its own stale links, free it with `into_data`
+    /// 
+    /// Safety: `node` must be a live node of this list
+    unsafe fn unlink_node(&mut self, node: NonNull<Node<T>>) {
+        // SAFETY: guaranteed by the caller, the neighbours are live as well
+        unsafe {
+            let (prev, next) = ((*node.as_ptr()).prev, (*node.as_ptr()).next);
+
+            match prev {
+                Some(prev_node) => (*prev_node.as_ptr()).next = next,
+                None => self.head = next,
+            }
+            match next {
+                Some(next_node) => (*next_node.as_ptr()).prev = prev,
+                None => self.tail = prev,
+            }
+        }
+        self.len -= 1;
     }
-    
+
+    /// Free a node that has been unlinked from the list and take its data out
+    /// 
+    /// Safety: `node` must have been allocated by `new_node` and nothing may
+    /// point to it any more
+    unsafe fn into_data(node: NonNull<Node<T>>) -> T {
+        // SAFETY: guaranteed by the caller
+        unsafe { Box::from_raw(node.as_ptr()).data }
+    }
+
     /// Reverse a doubly linked list
-    fn reverse(&mut self) {
-        self.is_empty();
+    pub fn reverse(&mut self) {
+        // Every node swaps its next and prev links
+        let mut current = self.head;
+        while let Some(node) = current {
+            // SAFETY: `node` is a live node of this list
+            unsafe {
+                let node = &mut *node.as_ptr();
+                mem::swap(&mut node.next, &mut node.prev);
+                // The old next node is now behind the prev link
+                current = node.prev;
+            }
+        }
+
+        // Update head and tail
+        mem::swap(&mut self.head, &mut self.tail);
+    }
+
+    /// Sort the list in ascending order
+    /// 
+    /// Stable merge sort in O(n log n) that relinks the nodes, the data is never moved
+    pub fn sort(&mut self)
+    where
+        T: Ord
+    {
+        self.sort_by(T::cmp);
+    }
 
-        // Handle single node
-        if self.head.as_ref().unwrap().borrow().next.is_none() {
+    /// Sort the list with a comparator function, keeping equal elements in order
+    /// 
+    /// The sort only follows the `next` links, the `prev` links and the tail
+    /// are rebuilt in one pass afterwards. If `cmp` panics the list keeps
+    /// every node, in an unspecified order.
+    /// 
+    /// args:
+    /// * `cmp`: returns how the first element is ordered relative to the second
+    pub fn sort_by<F>(&mut self, mut cmp: F)
+    where
+        F: FnMut(&T, &T) -> Ordering
+    {
+        if self.len < 2 {
             return;
         }
-        
-        // Save old head and tail
-        let old_head = self.head.clone();
-        let old_tail = self.tail.clone();
-        
-        // Start at the head
-        let mut current = old_head.clone();
-        
-        // Keep track of the previous node (in new direction)
-        let mut prev = None;
-        
-        // Iterate through the list
-        while let Some(current_ref) = current.clone() {
-            // Get the next node before we change any pointers
-            let next = {
-                let borrowed = current_ref.borrow();
-                borrowed.next.clone()
-            };
-            
-            // Update the current node's pointers
-            {
-                let mut current_mut = current_ref.borrow_mut();
-                current_mut.next = prev.clone();
-                current_mut.prev = next.clone();
-            }
-            
-            // Move forward: current becomes prev, next becomes current
-            prev = Some(current_ref);
-            current = next;
-        }
-        
-        // Update head and tail
-        self.head = old_tail;
-        self.tail = old_head;
-    }
-}
-
-#[allow(dead_code)]
-/// Run the program
-pub fn run() {
-    let mut doubly_ll = DoublyLinkedList::new();
-
-    doubly_ll.display();
-    doubly_ll.push_front(&10);
-    doubly_ll.push_front(&20);
-    doubly_ll.push_front(&30);
-    doubly_ll.push_front(&40);
-
-    doubly_ll.display();
-    
-    doubly_ll.push_back(&50);
-    doubly_ll.push_back(&60);
-    doubly_ll.display();
-    doubly_ll.get_tail_value();
-    
-    doubly_ll.push_back(&70);
-    doubly_ll.push_back(&80);
-    doubly_ll.display();
-    doubly_ll.get_tail_value();
-    
-    doubly_ll.push_after_node(&50, &969);
-    doubly_ll.display();
-    
-
-    doubly_ll.push_after_node(&969, &1000);
-    doubly_ll.display();
-    
-
-    println!("List Length: {}", doubly_ll.get_length());
-    
-    doubly_ll.delete_front();
-    doubly_ll.display();
-    
-    doubly_ll.delete_front();
-    doubly_ll.display();
-    
-    println!("---Delete Back---");
-
-    doubly_ll.delete_back(); 
-    doubly_ll.display();
-    
-
-    println!("---Delete at node---");
-
-    doubly_ll.delete_at_node(&60);
-    doubly_ll.display();
-    
-    doubly_ll.delete_at_node(&10);
-    doubly_ll.display();
-
-    doubly_ll.reverse();
-    doubly_ll.display();
-}
\ No newline at end of file
+
+        // Bottom-up: the nodes are taken one at a time and merged like a
+        // binary counter, so `runs[i]` is empty or a sorted run of 2^i nodes
+        let mut guard = SortGuard::new(self);
+        while let Some(node) = guard.list.head {
+            // SAFETY: the unsorted chain from the head is made of live nodes
+            guard.list.head = unsafe { (*node.as_ptr()).next.take() };
+            guard.right = Some(node);
+
+            let mut rank = 0;
+            while let Some(run) = guard.runs.get_mut(rank).and_then(Option::take) {
+                // The run holds earlier nodes than the carry, so it goes on the left
+                guard.left = Some(run);
+                guard.merge(&mut cmp);
+                guard.right = guard.merged.take();
+                rank += 1;
+            }
+            if rank == guard.runs.len() {
+                guard.runs.push(None);
+            }
+            guard.runs[rank] = guard.right.take();
+        }
+
+        // Fold the runs from the shortest one, the longer runs hold the earlier nodes
+        for rank in 0..guard.runs.len() {
+            if let Some(run) = guard.runs[rank].take() {
+                guard.right = guard.merged.take();
+                guard.left = Some(run);
+                guard.merge(&mut cmp);
+            }
+        }
+        // Dropping the guard links the sorted nodes back and rebuilds `prev`
+    }
+
+    /// Sort the list by the key extracted from each element, keeping equal keys in order
+    /// 
+    /// args:
+    /// * `key`: extracts the key to compare, called twice per comparison
+    pub fn sort_by_key<K, F>(&mut self, mut key: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K
+    {
+        self.sort_by(|a, b| key(a).cmp(&key(b)));
+    }
+
+    /// Insert a new node into a sorted list, after any nodes equal to it
+    /// 
+    /// args:
+    /// * `data`: the data to be stored in the new node
+    pub fn insert_sorted(&mut self, data: T)
+    where
+        T: Ord
+    {
+        let mut cursor = self.cursor_front_mut();
+        while cursor.current().is_some_and(|value| *value <= data) {
+            cursor.move_next();
+        }
+        cursor.insert_before(data);
+    }
+
+    /// Check if the list is sorted in ascending order
+    pub fn is_sorted(&self) -> bool
+    where
+        T: PartialOrd
+    {
+        self.iter().is_sorted()
+    }
+
+    /// Get a cursor pointing at the head node that can edit the list in place
+    /// 
+    /// An empty list gives a cursor at the "ghost" position, see [`CursorMut`]
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut {
+            current: self.head,
+            index: 0,
+            list: self
+        }
+    }
+
+    /// Get a cursor pointing at the tail node that can edit the list in place
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut {
+            current: self.tail,
+            index: self.len.saturating_sub(1),
+            list: self
+        }
+    }
+
+    /// Iterate over references to the data, from head to tail
+    /// 
+    /// Also iterates from tail to head through `.rev()`
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            head: self.head,
+            tail: self.tail,
+            len: self.len,
+            _marker: PhantomData
+        }
+    }
+
+    /// Iterate over mutable references to the data, from head to tail
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            head: self.head,
+            tail: self.tail,
+            len: self.len,
+            _marker: PhantomData
+        }
+    }
+}
+
+/// Owns the chains of `sort_by` while `cmp` runs, linked by `next` only
+/// 
+/// Dropping the guard joins every chain back into the list and rebuilds the
+/// `prev` links and the tail, so a panic in `cmp` keeps all the nodes, only
+/// their order is unspecified. The unsorted nodes stay in the list's own head
+/// meanwhile, and `len` never changes.
+/// 
+/// Every chain is made of live nodes of `list`, ends in `None`, and no node
+/// is in two chains.
+struct SortGuard<'a, T> {
+    list: &'a mut DoublyLinkedList<T>,
+    /// Sorted runs waiting to be merged
+    runs: Vec<Option<NonNull<Node<T>>>>,
+    left: Option<NonNull<Node<T>>>,
+    right: Option<NonNull<Node<T>>>,
+    merged: Option<NonNull<Node<T>>>,
+}
+
+impl<'a, T> SortGuard<'a, T> {
+    fn new(list: &'a mut DoublyLinkedList<T>) -> Self {
+        SortGuard { list, runs: Vec::new(), left: None, right: None, merged: None }
+    }
+
+    /// Merge `left` and `right` into the empty `merged`, taking from `left` first on ties
+    fn merge<F>(&mut self, cmp: &mut F)
+    where
+        F: FnMut(&T, &T) -> Ordering
+    {
+        let mut tail: Option<NonNull<Node<T>>> = None;
+
+        // SAFETY: the chains are made of live nodes, each node is cut off its
+        // chain before it is linked to `merged`
+        unsafe {
+            while let (Some(l), Some(r)) = (self.left, self.right) {
+                let source = if cmp(&(*l.as_ptr()).data, &(*r.as_ptr()).data) != Ordering::Greater {
+                    &mut self.left
+                } else {
+                    &mut self.right
+                };
+                let node = source.take().unwrap();
+                *source = (*node.as_ptr()).next.take();
+
+                match tail {
+                    Some(tail_node) => (*tail_node.as_ptr()).next = Some(node),
+                    None => self.merged = Some(node),
+                }
+                tail = Some(node);
+            }
+
+            // One side ran out, the rest of the other one is already in order
+            let rest = self.left.take().or(self.right.take());
+            match tail {
+                Some(tail_node) => (*tail_node.as_ptr()).next = rest,
+                None => self.merged = rest,
+            }
+        }
+    }
+}
+
+impl<T> Drop for SortGuard<'_, T> {
+    fn drop(&mut self) {
+        let rest = self.list.head.take();
+        let chains = [self.merged.take(), self.left.take(), self.right.take()]
+            .into_iter()
+            .chain(self.runs.drain(..))
+            .chain([rest]);
+
+        let mut head = None;
+        let mut tail: Option<NonNull<Node<T>>> = None;
+        for chain in chains {
+            let mut current = chain;
+            while let Some(node) = current {
+                // SAFETY: see the invariants on `SortGuard`
+                unsafe {
+                    (*node.as_ptr()).prev = tail;
+                    match tail {
+                        Some(tail_node) => (*tail_node.as_ptr()).next = Some(node),
+                        None => head = Some(node),
+                    }
+                    current = (*node.as_ptr()).next;
+                }
+                tail = Some(node);
+            }
+        }
+
+        self.list.head = head;
+        self.list.tail = tail;
+    }
+}
+
+/// Cursor returned by [`DoublyLinkedList::cursor_front_mut`] and
+/// [`DoublyLinkedList::cursor_back_mut`]
+/// 
+/// Modeled on `std::collections::linked_list::CursorMut`. The cursor points
+/// at a node, or at a "ghost" position between the tail and the head when it
+/// runs off either end. Every operation is O(1).
+pub struct CursorMut<'a, T> {
+    list: &'a mut DoublyLinkedList<T>,
+    current: Option<NonNull<Node<T>>>,
+    index: usize,
+}
+
+// Every `unsafe` block in the cursor relies on `current` being a live node of
+// `list`. The cursor holds `&mut` to the list, so only the cursor itself can
+// unlink nodes, and `remove_current` moves off a node before freeing it.
+impl<T> CursorMut<'_, T> {
+    /// Get the index of the node under the cursor, `None` at the ghost position
+    pub fn index(&self) -> Option<usize> {
+        self.current.map(|_| self.index)
+    }
+
+    /// Get the data under the cursor, `None` at the ghost position
+    pub fn current(&mut self) -> Option<&mut T> {
+        // SAFETY: see the comment above `impl CursorMut`
+        self.current.map(|node| unsafe { &mut (*node.as_ptr()).data })
+    }
+
+    /// Move to the next node
+    /// 
+    /// From the tail this moves to the ghost position, and from the ghost
+    /// position to the head
+    pub fn move_next(&mut self) {
+        match self.current {
+            Some(node) => {
+                // SAFETY: see the comment above `impl CursorMut`
+                self.current = unsafe { (*node.as_ptr()).next };
+                self.index += 1;
+            }
+            None => {
+                self.current = self.list.head;
+                self.index = 0;
+            }
+        }
+    }
+
+    /// Move to the previous node
+    /// 
+    /// From the head this moves to the ghost position, and from the ghost
+    /// position to the tail
+    pub fn move_prev(&mut self) {
+        match self.current {
+            Some(node) => {
+                // SAFETY: see the comment above `impl CursorMut`
+                self.current = unsafe { (*node.as_ptr()).prev };
+                self.index = match self.current {
+                    Some(_) => self.index - 1,
+                    None => self.list.len,
+                };
+            }
+            None => {
+                self.current = self.list.tail;
+                self.index = self.list.len.saturating_sub(1);
+            }
+        }
+    }
+
+    /// Insert a new node after the cursor
+    /// 
+    /// At the ghost position the node becomes the new head
+    pub fn insert_after(&mut self, data: T) {
+        match self.current {
+            Some(node) => {
+                // SAFETY: see the comment above `impl CursorMut`
+                unsafe {
+                    self.list.link_after(node, data);
+                }
+            }
+            None => {
+                self.list.push_front(data);
+                self.index += 1;
+            }
+        }
+    }
+
+    /// Insert a new node before the cursor
+    /// 
+    /// At the ghost position the node becomes the new tail
+    pub fn insert_before(&mut self, data: T) {
+        let prev = match self.current {
+            // SAFETY: see the comment above `impl CursorMut`
+            Some(node) => unsafe { (*node.as_ptr()).prev },
+            None => self.list.tail,
+        };
+
+        match prev {
+            // SAFETY: the previous node is a live node of the list as well
+            Some(prev_node) => unsafe {
+                self.list.link_after(prev_node, data);
+            },
+            None => self.list.push_front(data),
+        }
+        self.index += 1;
+    }
+
+    /// Remove the node under the cursor and move to the next one
+    /// 
+    /// return:
+    /// * `Option<T>` -> The removed data, `None` at the ghost position
+    pub fn remove_current(&mut self) -> Option<T> {
+        let node = self.current?;
+
+        // SAFETY: see the comment above `impl CursorMut`, the cursor has moved
+        // on before the node is freed
+        unsafe {
+            self.current = (*node.as_ptr()).next;
+            self.list.unlink_node(node);
+            Some(DoublyLinkedList::into_data(node))
+        }
+    }
+
+    /// Split the list in two after the cursor
+    /// 
+    /// return:
+    /// * `DoublyLinkedList<T>` -> Everything after the cursor, or the whole
+    ///   list when the cursor is at the ghost position
+    pub fn split_after(&mut self) -> DoublyLinkedList<T> {
+        let node = match self.current {
+            Some(node) => node,
+            None => {
+                self.index = 0;
+                return mem::take(self.list);
+            }
+        };
+
+        // SAFETY: see the comment above `impl CursorMut`
+        let head = unsafe {
+            match (*node.as_ptr()).next.take() {
+                Some(head) => {
+                    (*head.as_ptr()).prev = None;
+                    head
+                }
+                None => return DoublyLinkedList::new(),
+            }
+        };
+
+        let len = self.list.len - self.index - 1;
+        self.list.len -= len;
+        DoublyLinkedList {
+            head: Some(head),
+            tail: self.list.tail.replace(node),
+            len,
+            _marker: PhantomData
+        }
+    }
+}
+
+// The iterators below walk raw pointers while borrowing the list itself:
+// `iter` holds `&self` so no node can be changed or freed while it lives, and
+// `iter_mut` holds `&mut self` and yields each node's data exactly once,
+// `len` stops the two ends from meeting on the same node.
+
+/// Borrowing iterator returned by [`DoublyLinkedList::iter`]
+pub struct Iter<'a, T> {
+    head: Option<NonNull<Node<T>>>,
+    tail: Option<NonNull<Node<T>>>,
+    len: usize,
+    _marker: PhantomData<&'a Node<T>>,
+}
+
+// SAFETY: `Iter` only hands out `&T`, like `&DoublyLinkedList<T>` would
+unsafe impl<T: Sync> Send for Iter<'_, T> {}
+unsafe impl<T: Sync> Sync for Iter<'_, T> {}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.head.map(|node| {
+            // SAFETY: see the comment above `Iter`
+            let node = unsafe { &*node.as_ptr() };
+            self.head = node.next;
+            self.len -= 1;
+            &node.data
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.tail.map(|node| {
+            // SAFETY: see the comment above `Iter`
+            let node = unsafe { &*node.as_ptr() };
+            self.tail = node.prev;
+            self.len -= 1;
+            &node.data
+        })
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+// Written out because deriving it would require `T: Clone`
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Iter {
+            head: self.head,
+            tail: self.tail,
+            len: self.len,
+            _marker: PhantomData
+        }
+    }
+}
+
+/// Mutable iterator returned by [`DoublyLinkedList::iter_mut`]
+pub struct IterMut<'a, T> {
+    head: Option<NonNull<Node<T>>>,
+    tail: Option<NonNull<Node<T>>>,
+    len: usize,
+    _marker: PhantomData<&'a mut Node<T>>,
+}
+
+// SAFETY: `IterMut` only hands out `&mut T`, like `&mut DoublyLinkedList<T>` would
+unsafe impl<T: Send> Send for IterMut<'_, T> {}
+unsafe impl<T: Sync> Sync for IterMut<'_, T> {}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.head.map(|node| {
+            // SAFETY: see the comment above `Iter`
+            let node = unsafe { &mut *node.as_ptr() };
+            self.head = node.next;
+            self.len -= 1;
+            &mut node.data
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.tail.map(|node| {
+            // SAFETY: see the comment above `Iter`
+            let node = unsafe { &mut *node.as_ptr() };
+            self.tail = node.prev;
+            self.len -= 1;
+            &mut node.data
+        })
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+/// Consuming iterator returned by [`DoublyLinkedList::into_iter`]
+pub struct IntoIter<T>(DoublyLinkedList<T>);
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len, Some(self.0.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.pop_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> IntoIterator for DoublyLinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a DoublyLinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut DoublyLinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T> Index<usize> for DoublyLinkedList<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        let len = self.len;
+        self.get(index)
+            .unwrap_or_else(|| panic!("{}", ListError::IndexOutOfBounds { index, len }))
+    }
+}
+
+impl<T> IndexMut<usize> for DoublyLinkedList<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let len = self.len;
+        self.get_mut(index)
+            .unwrap_or_else(|| panic!("{}", ListError::IndexOutOfBounds { index, len }))
+    }
+}
+
+impl<T> Extend<T> for DoublyLinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for data in iter {
+            self.push_back(data);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for DoublyLinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T: Clone> Clone for DoublyLinkedList<T> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: PartialEq> PartialEq for DoublyLinkedList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for DoublyLinkedList<T> {}
+
+impl<T: Hash> Hash for DoublyLinkedList<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        for data in self.iter() {
+            data.hash(state);
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for DoublyLinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for DoublyLinkedList<T> {
+    /// Renders the list from head to tail as `a -> b -> None`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for data in self.iter() {
+            write!(f, "{} -> ", data)?;
+        }
+        write!(f, "None")
+    }
+}
+
+// These tests walk every path through the raw pointer code, run them under
+// Miri with `cargo +nightly miri test doubly_ll` after changing it
+#[cfg(test)]
+mod tests {
+    use std::{panic::{self, AssertUnwindSafe}, rc::Rc};
+
+    use crate::linked_list::test_utils;
+    use super::*;
+
+    /// Values walked from head to tail and from tail to head
+    fn both_ways<T: Clone>(list: &DoublyLinkedList<T>) -> (Vec<T>, Vec<T>) {
+        (list.iter().cloned().collect(), list.iter().rev().cloned().collect())
+    }
+
+    #[test]
+    fn push_front_links_both_ways() {
+        let mut list = DoublyLinkedList::new();
+        list.check_links();
+        for value in 1..=4 {
+            list.push_front(value);
+            list.check_links();
+        }
+
+        assert_eq!(both_ways(&list), (vec![4, 3, 2, 1], vec![1, 2, 3, 4]));
+        assert_eq!(list.get_tail_value(), Some(&1));
+    }
+
+    #[test]
+    fn push_back_links_both_ways() {
+        let mut list = DoublyLinkedList::new();
+        for value in 1..=4 {
+            list.push_back(value);
+            list.check_links();
+        }
+
+        assert_eq!(both_ways(&list), (vec![1, 2, 3, 4], vec![4, 3, 2, 1]));
+        assert_eq!(list.get_tail_value(), Some(&4));
+    }
+
+    #[test]
+    fn mixed_pushes_link_both_ways() {
+        let mut list = DoublyLinkedList::new();
+        list.push_back(2);
+        list.push_front(1);
+        list.push_back(3);
+        list.push_front(0);
+        list.check_links();
+
+        assert_eq!(both_ways(&list), (vec![0, 1, 2, 3], vec![3, 2, 1, 0]));
+        assert_eq!(list.len(), 4);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "check_links only runs in debug builds")]
+    #[should_panic(expected = "does not link back")]
+    fn check_links_catches_a_broken_prev_link() {
+        let list: DoublyLinkedList<_> = (1..=3).collect();
+        let tail = list.tail.expect("list is not empty");
+        // SAFETY: `tail` is a live node, only its back link is broken
+        unsafe { (*tail.as_ptr()).prev = None };
+        list.check_links();
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "check_links only runs in debug builds")]
+    #[should_panic(expected = "len does not match")]
+    fn check_links_catches_a_wrong_len() {
+        let mut list: DoublyLinkedList<_> = (1..=3).collect();
+        list.len += 1;
+        list.check_links();
+    }
+
+    #[test]
+    fn cursor_move_prev_wraps_through_the_ghost() {
+        let mut list: DoublyLinkedList<_> = (1..=3).collect();
+        let mut cursor = list.cursor_front_mut();
+
+        cursor.move_prev();
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.index(), None);
+        cursor.move_prev();
+        assert_eq!(cursor.current(), Some(&mut 3));
+        assert_eq!(cursor.index(), Some(2));
+        cursor.move_next();
+        assert_eq!(cursor.index(), None);
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&mut 1));
+        assert_eq!(cursor.index(), Some(0));
+
+        list.check_links();
+        assert_eq!(both_ways(&list), (vec![1, 2, 3], vec![3, 2, 1]));
+    }
+
+    #[test]
+    fn cursor_inserts_at_the_ghost_go_to_either_end() {
+        let mut list: DoublyLinkedList<_> = (1..=2).collect();
+        let mut cursor = list.cursor_back_mut();
+        cursor.move_next();
+        assert_eq!(cursor.index(), None);
+
+        cursor.insert_after(0);
+        cursor.insert_before(3);
+        assert_eq!(cursor.current(), None);
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&mut 0));
+        assert_eq!(cursor.index(), Some(0));
+
+        list.check_links();
+        assert_eq!(both_ways(&list), (vec![0, 1, 2, 3], vec![3, 2, 1, 0]));
+    }
+
+    #[test]
+    fn cursor_remove_current_at_the_tail_moves_to_the_ghost() {
+        let mut list: DoublyLinkedList<_> = (1..=3).collect();
+        let mut cursor = list.cursor_back_mut();
+
+        assert_eq!(cursor.remove_current(), Some(3));
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.index(), None);
+        assert_eq!(cursor.remove_current(), None);
+        cursor.move_prev();
+        assert_eq!(cursor.current(), Some(&mut 2));
+        assert_eq!(cursor.index(), Some(1));
+
+        list.check_links();
+        assert_eq!(both_ways(&list), (vec![1, 2], vec![2, 1]));
+    }
+
+    #[test]
+    fn cursor_split_after() {
+        let mut list: DoublyLinkedList<_> = (1..=5).collect();
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+
+        let tail = cursor.split_after();
+        assert_eq!(cursor.index(), Some(1));
+        tail.check_links();
+        list.check_links();
+        assert_eq!(both_ways(&tail), (vec![3, 4, 5], vec![5, 4, 3]));
+        assert_eq!(both_ways(&list), (vec![1, 2], vec![2, 1]));
+
+        // At the ghost position
+        let mut cursor = list.cursor_back_mut();
+        cursor.move_next();
+        let everything = cursor.split_after();
+        assert_eq!(cursor.index(), None);
+        cursor.move_next();
+        assert_eq!(cursor.current(), None);
+        everything.check_links();
+        list.check_links();
+        assert_eq!(both_ways(&everything), (vec![1, 2], vec![2, 1]));
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn cursor_index_follows_edits() {
+        let mut list: DoublyLinkedList<_> = [10, 20, 30].into_iter().collect();
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        assert_eq!(cursor.index(), Some(1));
+
+        cursor.insert_before(15);
+        assert_eq!(cursor.index(), Some(2));
+        assert_eq!(cursor.current(), Some(&mut 20));
+        cursor.insert_after(25);
+        assert_eq!(cursor.index(), Some(2));
+        assert_eq!(cursor.remove_current(), Some(20));
+        assert_eq!(cursor.index(), Some(2));
+        assert_eq!(cursor.current(), Some(&mut 25));
+        cursor.move_prev();
+        assert_eq!(cursor.index(), Some(1));
+        assert_eq!(cursor.current(), Some(&mut 15));
+
+        list.check_links();
+        assert_eq!(both_ways(&list), (vec![10, 15, 25, 30], vec![30, 25, 15, 10]));
+    }
+
+    /// Check the links and compare the list with `expected` from both ends
+    fn assert_list(list: &DoublyLinkedList<i32>, expected: &[i32]) {
+        list.check_links();
+        let backwards: Vec<_> = expected.iter().rev().copied().collect();
+        assert_eq!(both_ways(list), (expected.to_vec(), backwards));
+        assert_eq!(list.len(), expected.len());
+    }
+
+    #[test]
+    fn push_and_pop_at_both_ends() {
+        let mut list = DoublyLinkedList::new();
+        assert_eq!(list.pop_front(), None);
+        assert_eq!(list.pop_back(), None);
+
+        list.push_back(2);
+        list.push_front(1);
+        list.push_back(3);
+        assert_list(&list, &[1, 2, 3]);
+
+        assert_eq!(list.pop_back(), Some(3));
+        assert_list(&list, &[1, 2]);
+        assert_eq!(list.pop_front(), Some(1));
+        assert_list(&list, &[2]);
+        assert_eq!(list.pop_back(), Some(2));
+        assert_list(&list, &[]);
+        assert_eq!(list.pop_front(), None);
+
+        list.push_front(4);
+        assert_eq!(list.pop_front(), Some(4));
+        assert_list(&list, &[]);
+    }
+
+    #[test]
+    fn insert_at_index_at_every_position() {
+        let mut list = DoublyLinkedList::new();
+        assert!(list.insert_at_index(0, 2).is_ok());
+        assert!(list.insert_at_index(0, 0).is_ok());
+        assert!(list.insert_at_index(2, 3).is_ok());
+        assert!(list.insert_at_index(1, 1).is_ok());
+        assert_list(&list, &[0, 1, 2, 3]);
+
+        assert_eq!(
+            list.insert_at_index(5, 9),
+            Err(ListError::IndexOutOfBounds { index: 5, len: 4 })
+        );
+        assert_list(&list, &[0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn push_after_node_links_one_node_after_the_first_match() {
+        let mut list: DoublyLinkedList<_> = [1, 2, 1].into_iter().collect();
+        assert!(list.push_after_node(&1, 5).is_ok());
+        assert_list(&list, &[1, 5, 2, 1]);
+
+        // After the tail, the new node has to become the tail
+        assert!(list.push_after_node(&2, 6).is_ok());
+        assert!(list.push_after_node(&6, 7).is_ok());
+        assert_list(&list, &[1, 5, 2, 6, 7, 1]);
+        let mut single: DoublyLinkedList<_> = [3].into_iter().collect();
+        assert!(single.push_after_node(&3, 4).is_ok());
+        assert_list(&single, &[3, 4]);
+        assert_eq!(single.get_tail_value(), Some(&4));
+
+        assert_eq!(list.push_after_node(&9, 0), Err(ListError::NotFound));
+        assert_list(&list, &[1, 5, 2, 6, 7, 1]);
+        let mut empty = DoublyLinkedList::new();
+        assert_eq!(empty.push_after_node(&1, 0), Err(ListError::Empty));
+        assert_list(&empty, &[]);
+    }
+
+    #[test]
+    fn push_after_all_nodes_skips_the_inserted_nodes() {
+        let mut list: DoublyLinkedList<_> = [1, 2, 1, 1].into_iter().collect();
+        assert_eq!(list.push_after_all_nodes(&1, 0), Ok(3));
+        assert_list(&list, &[1, 0, 2, 1, 0, 1, 0]);
+        assert_eq!(list.get_tail_value(), Some(&0));
+
+        // The inserted copies match as well and must not be matched again
+        assert_eq!(list.push_after_all_nodes(&2, 2), Ok(1));
+        assert_list(&list, &[1, 0, 2, 2, 1, 0, 1, 0]);
+        assert_eq!(list.push_after_all_nodes(&0, 0), Ok(3));
+        assert_list(&list, &[1, 0, 0, 2, 2, 1, 0, 0, 1, 0, 0]);
+
+        assert_eq!(list.push_after_all_nodes(&9, 0), Err(ListError::NotFound));
+        assert_eq!(list.len(), 11);
+        let mut empty = DoublyLinkedList::new();
+        assert_eq!(empty.push_after_all_nodes(&1, 1), Err(ListError::Empty));
+        assert_list(&empty, &[]);
+    }
+
+    #[test]
+    fn remove_at_from_both_halves() {
+        let mut list: DoublyLinkedList<_> = (0..6).collect();
+        assert_eq!(list.remove_at(4), Ok(4));
+        assert_list(&list, &[0, 1, 2, 3, 5]);
+        assert_eq!(list.remove_at(1), Ok(1));
+        assert_list(&list, &[0, 2, 3, 5]);
+        assert_eq!(list.remove_at(3), Ok(5));
+        assert_list(&list, &[0, 2, 3]);
+        assert_eq!(list.remove_at(0), Ok(0));
+        assert_list(&list, &[2, 3]);
+
+        assert_eq!(list.remove_at(2), Err(ListError::IndexOutOfBounds { index: 2, len: 2 }));
+        assert_eq!(list.remove_at(0), Ok(2));
+        assert_eq!(list.remove_at(0), Ok(3));
+        assert_list(&list, &[]);
+    }
+
+    #[test]
+    fn split_off_at_every_position() {
+        for at in 0..=4 {
+            let mut list: DoublyLinkedList<_> = (0..4).collect();
+            let back = list.split_off(at).expect("at is in range");
+            let expected: Vec<_> = (0..4).collect();
+            assert_list(&list, &expected[..at]);
+            assert_list(&back, &expected[at..]);
+        }
+
+        let mut list: DoublyLinkedList<_> = (0..4).collect();
+        assert!(list.split_off(5).is_err());
+        assert_list(&list, &[0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn append_and_prepend_with_empty_sides() {
+        let mut list = DoublyLinkedList::new();
+        let mut other: DoublyLinkedList<_> = (3..5).collect();
+        list.append(&mut other);
+        assert_list(&list, &[3, 4]);
+        assert_list(&other, &[]);
+
+        list.append(&mut other);
+        assert_list(&list, &[3, 4]);
+        other.extend([5, 6]);
+        list.append(&mut other);
+        assert_list(&list, &[3, 4, 5, 6]);
+        assert_list(&other, &[]);
+
+        list.prepend(DoublyLinkedList::new());
+        list.prepend((1..3).collect());
+        assert_list(&list, &[1, 2, 3, 4, 5, 6]);
+
+        let mut empty = DoublyLinkedList::new();
+        empty.prepend((0..2).collect());
+        assert_list(&empty, &[0, 1]);
+    }
+
+    #[test]
+    fn splice_at_every_position() {
+        for index in 0..=3 {
+            let mut list: DoublyLinkedList<_> = (0..3).collect();
+            assert!(list.splice_at(index, [10, 11].into_iter().collect()).is_ok());
+            let mut expected = vec![0, 1, 2];
+            expected.splice(index..index, [10, 11]);
+            assert_list(&list, &expected);
+        }
+
+        let mut list: DoublyLinkedList<_> = (0..3).collect();
+        assert!(list.splice_at(1, DoublyLinkedList::new()).is_ok());
+        assert!(list.splice_at(4, (0..2).collect()).is_err());
+        assert_list(&list, &[0, 1, 2]);
+    }
+
+    #[test]
+    fn sort_by_is_stable_and_relinks_both_ways() {
+        let mut list: DoublyLinkedList<(i32, char)> = DoublyLinkedList::new();
+        list.sort();
+        list.push_back((1, 'a'));
+        list.sort();
+        assert!(list.is_sorted());
+
+        let mut list: DoublyLinkedList<_> =
+            [(3, 'a'), (1, 'b'), (3, 'c'), (2, 'd'), (1, 'e'), (0, 'f'), (2, 'g')].into_iter().collect();
+        list.sort_by_key(|(key, _)| *key);
+        list.check_links();
+        let expected = [(0, 'f'), (1, 'b'), (1, 'e'), (2, 'd'), (2, 'g'), (3, 'a'), (3, 'c')];
+        assert!(list.iter().eq(expected.iter()));
+        assert!(list.iter().rev().eq(expected.iter().rev()));
+
+        let mut list: DoublyLinkedList<_> = (0..50).rev().collect();
+        list.sort_by(|a, b| a.cmp(b));
+        let expected: Vec<_> = (0..50).collect();
+        assert_list(&list, &expected);
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    enum CursorOp {
+        MoveNext,
+        MovePrev,
+        InsertAfter,
+        InsertBefore,
+        RemoveCurrent,
+        SplitAfter,
+    }
+
+    #[test]
+    fn cursor_every_operation_at_head_tail_and_ghost() {
+        use CursorOp::*;
+
+        for len in [0, 1, 3] {
+            // `None` is the ghost position
+            let mut starts = vec![None];
+            if len > 0 {
+                starts.extend([Some(0), Some(len - 1)]);
+            }
+
+            for start in starts {
+                for op in [MoveNext, MovePrev, InsertAfter, InsertBefore, RemoveCurrent, SplitAfter] {
+                    let mut list: DoublyLinkedList<_> = (0..len as i32).collect();
+                    let mut model: Vec<_> = (0..len as i32).collect();
+                    let mut position = start;
+
+                    let mut cursor = match start {
+                        Some(0) => list.cursor_front_mut(),
+                        Some(_) => list.cursor_back_mut(),
+                        None => {
+                            let mut cursor = list.cursor_front_mut();
+                            if len > 0 {
+                                cursor.move_prev();
+                            }
+                            cursor
+                        }
+                    };
+                    assert_eq!(cursor.index(), position);
+
+                    let mut split = None;
+                    match op {
+                        MoveNext => {
+                            cursor.move_next();
+                            position = match position {
+                                Some(index) if index + 1 < model.len() => Some(index + 1),
+                                Some(_) => None,
+                                None => (!model.is_empty()).then_some(0),
+                            };
+                        }
+                        MovePrev => {
+                            cursor.move_prev();
+                            position = match position {
+                                Some(index) => index.checked_sub(1),
+                                None => model.len().checked_sub(1),
+                            };
+                        }
+                        InsertAfter => {
+                            cursor.insert_after(10);
+                            match position {
+                                Some(index) => model.insert(index + 1, 10),
+                                None => model.insert(0, 10),
+                            }
+                        }
+                        InsertBefore => {
+                            cursor.insert_before(10);
+                            match position {
+                                Some(index) => {
+                                    model.insert(index, 10);
+                                    position = Some(index + 1);
+                                }
+                                None => model.push(10),
+                            }
+                        }
+                        RemoveCurrent => {
+                            let removed = cursor.remove_current();
+                            match position {
+                                Some(index) => {
+                                    assert_eq!(removed, Some(model.remove(index)));
+                                    position = (index < model.len()).then_some(index);
+                                }
+                                None => assert_eq!(removed, None),
+                            }
+                        }
+                        SplitAfter => {
+                            let back = cursor.split_after();
+                            let at = position.map_or(0, |index| index + 1);
+                            split = Some((back, model.split_off(at)));
+                        }
+                    }
+
+                    let context = format!("{:?} at {:?} of {}", op, start, len);
+                    assert_eq!(cursor.index(), position, "{}", context);
+                    let expected = position.map(|index| model[index]);
+                    assert_eq!(cursor.current().copied(), expected, "{}", context);
+
+                    assert_list(&list, &model);
+                    if let Some((back, back_model)) = split {
+                        assert_list(&back, &back_model);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn iter_mut_from_both_ends() {
+        for len in 0..6 {
+            let mut list: DoublyLinkedList<_> = (0..len).collect();
+            let mut iter = list.iter_mut();
+            let mut seen = Vec::new();
+            let mut from_back = false;
+
+            while let Some(value) = if from_back { iter.next_back() } else { iter.next() } {
+                seen.push(*value);
+                *value += 100;
+                from_back = !from_back;
+                assert_eq!(iter.len(), len as usize - seen.len());
+            }
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next_back(), None);
+
+            seen.sort();
+            assert_eq!(seen, (0..len).collect::<Vec<_>>());
+            let expected: Vec<_> = (100..100 + len).collect();
+            assert_list(&list, &expected);
+        }
+    }
+
+    #[test]
+    fn drop_runs_once_for_every_element() {
+        let token = Rc::new(());
+        let mut list: DoublyLinkedList<_> = (0..6).map(|_| Rc::clone(&token)).collect();
+        list.reverse();
+        list.sort_by(|_, _| Ordering::Equal);
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        cursor.remove_current();
+        let back = cursor.split_after();
+        list.check_links();
+        back.check_links();
+        assert_eq!(Rc::strong_count(&token), 6);
+
+        drop(back);
+        drop(list);
+        assert_eq!(Rc::strong_count(&token), 1);
+    }
+
+    test_utils::leak_tests!(DoublyLinkedList, split_off, remove_at);
+
+    #[test]
+    fn sort_by_keeps_every_node_when_cmp_panics() {
+        // Sorting 10 nodes takes fewer than 30 comparisons, so this panics in
+        // every phase of the sort and finally not at all
+        for limit in 0..30 {
+            let mut list: DoublyLinkedList<_> = [3, 7, 0, 9, 1, 8, 2, 6, 4, 5].into_iter().collect();
+            let mut calls = 0;
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                list.sort_by(|a, b| {
+                    calls += 1;
+                    assert!(calls <= limit, "comparator gave up");
+                    a.cmp(b)
+                });
+            }));
+
+            assert_eq!(result.is_err(), calls > limit);
+            list.check_links();
+            assert_eq!(list.len(), 10);
+            let (mut sorted, _) = both_ways(&list);
+            sorted.sort();
+            assert_eq!(sorted, (0..10).collect::<Vec<_>>(), "limit {}", limit);
+            assert!(result.is_err() || list.is_sorted());
+
+            list.push_back(10);
+            assert_eq!(list.remove_at(10), Ok(10));
+        }
+    }
+
+    #[test]
+    fn get_set_and_index_mut_walk_from_the_closer_end() {
+        // 7 nodes: indices 0..3 are reached from the head, 3..7 from the tail
+        let mut list: DoublyLinkedList<_> = (0..7).collect();
+        for index in 0..7 {
+            assert_eq!(list.get(index), Some(&(index as i32)));
+        }
+        assert_eq!(list.get(7), None);
+
+        assert_eq!(list.set(0, 10), Ok(0));
+        assert_eq!(list.set(6, 16), Ok(6));
+        assert_eq!(list.set(7, 17), Err(ListError::IndexOutOfBounds { index: 7, len: 7 }));
+        *list.get_mut(2).unwrap() += 10;
+        *list.get_mut(4).unwrap() += 10;
+        assert_eq!(list.get_mut(7), None);
+        list[3] = 13;
+        list[5] = 15;
+
+        assert_list(&list, &[10, 1, 12, 13, 14, 15, 16]);
+        assert_eq!((list[1], list[5]), (1, 15));
+
+        let mut empty = DoublyLinkedList::<i32>::new();
+        assert_eq!(empty.get_mut(0), None);
+        assert_eq!(empty.set(0, 1), Err(ListError::IndexOutOfBounds { index: 0, len: 0 }));
+    }
+
+    #[test]
+    #[should_panic(expected = "index 3 out of range for list of length 3")]
+    fn index_past_the_end_panics() {
+        let list: DoublyLinkedList<_> = (1..=3).collect();
+        let _ = list[3];
+    }
+
+    #[test]
+    #[should_panic(expected = "index 0 out of range for list of length 0")]
+    fn index_mut_on_an_empty_list_panics() {
+        let mut list = DoublyLinkedList::new();
+        list[0] = 1;
+    }
+
+    #[test]
+    fn find_by_and_contains() {
+        let list: DoublyLinkedList<_> = [3, 8, 5, 8].into_iter().collect();
+        assert_eq!(list.find_by(|&value| value > 4), Some(&8));
+        assert_eq!(list.find_by(|&value| value == 3), Some(&3));
+        assert_eq!(list.find_by(|&value| value == 5), Some(&5));
+        assert_eq!(list.find_by(|&value| value > 8), None);
+        assert!(list.contains(&3));
+        assert!(list.contains(&8));
+        assert!(!list.contains(&4));
+
+        let empty = DoublyLinkedList::<i32>::new();
+        assert_eq!(empty.find_by(|_| true), None);
+        assert!(!empty.contains(&0));
+    }
+
+    #[test]
+    fn remove_first_takes_only_the_first_match() {
+        let mut list: DoublyLinkedList<_> = [1, 2, 2, 3, 1].into_iter().collect();
+        assert_eq!(list.remove_first(&4), None);
+        assert_list(&list, &[1, 2, 2, 3, 1]);
+        assert_eq!(list.remove_first(&1), Some(1));
+        assert_list(&list, &[2, 2, 3, 1]);
+        assert_eq!(list.remove_first(&2), Some(2));
+        assert_list(&list, &[2, 3, 1]);
+        assert_eq!(list.remove_first(&1), Some(1));
+        assert_list(&list, &[2, 3]);
+        assert_eq!(list.remove_first(&3), Some(3));
+        assert_eq!(list.remove_first(&2), Some(2));
+        assert_list(&list, &[]);
+        assert_eq!(list.remove_first(&2), None);
+    }
+
+    #[test]
+    fn remove_all_at_the_head_tail_and_in_runs() {
+        let mut list: DoublyLinkedList<_> = [1, 1, 2, 1, 1, 3, 1].into_iter().collect();
+        assert_eq!(list.remove_all(&4), 0);
+        assert_list(&list, &[1, 1, 2, 1, 1, 3, 1]);
+        assert_eq!(list.remove_all(&1), 5);
+        assert_list(&list, &[2, 3]);
+        // The list still works at both ends afterwards
+        list.push_back(4);
+        list.push_front(0);
+        assert_list(&list, &[0, 2, 3, 4]);
+
+        let mut same: DoublyLinkedList<_> = [7, 7, 7].into_iter().collect();
+        assert_eq!(same.remove_all(&7), 3);
+        assert_list(&same, &[]);
+        assert_eq!(same.remove_all(&7), 0);
+    }
+
+    #[test]
+    fn retain_keeps_the_matching_nodes_in_order() {
+        let mut list: DoublyLinkedList<_> = (0..10).collect();
+        let mut seen = Vec::new();
+        list.retain(|&value| {
+            seen.push(value);
+            value % 3 != 0
+        });
+        // Every node is visited once, in order
+        assert_eq!(seen, (0..10).collect::<Vec<_>>());
+        assert_list(&list, &[1, 2, 4, 5, 7, 8]);
+
+        list.retain(|_| true);
+        assert_list(&list, &[1, 2, 4, 5, 7, 8]);
+        list.retain(|_| false);
+        assert_list(&list, &[]);
+        list.retain(|_| false);
+        list.push_back(1);
+        assert_list(&list, &[1]);
+    }
+
+    #[test]
+    fn std_traits() {
+        test_utils::check_std_traits::<DoublyLinkedList<i32>>("1 -> 2 -> 3 -> None", "None");
+    }
+}
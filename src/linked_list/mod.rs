@@ -1,3 +1,15 @@
 pub mod singly_ll;
 pub mod doubly_ll;
-pub mod circular_ll;
\ No newline at end of file
+pub mod circular_ll;
+pub mod circular_doubly_ll;
+pub mod vec_ll;
+pub mod algorithms;
+
+#[cfg(test)]
+mod test_utils;
+
+pub use singly_ll::SinglyLinkedList;
+pub use doubly_ll::DoublyLinkedList;
+pub use circular_ll::CircularLinkedList;
+pub use circular_doubly_ll::CircularDoublyLinkedList;
+pub use vec_ll::VecLinkedList;
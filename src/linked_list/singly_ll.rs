@@ -1,41 +1,61 @@
+use std::{cmp::Ordering, fmt, hash::{Hash, Hasher}, ops::{Index, IndexMut}, ptr::NonNull};
+
+use crate::ListError;
+
 #[derive(Debug)]
-struct Node<'a, T> {
-    data: &'a T,
-    next: Option<Box<Node<'a, T>>>,
+struct Node<T> {
+    data: T,
+    next: Option<Box<Node<T>>>,
+}
+
+pub struct SinglyLinkedList<T> {
+    head: Option<Box<Node<T>>>,
+    len: usize,
 }
 
-struct SinglyLinkedList<'a, T> {
-    head: Option<Box<Node<'a, T>>>
+impl<T> Default for SinglyLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// The default drop of `Option<Box<Node>>` recurses once per node and
+// overflows the stack on long lists, so unlink the nodes in a loop instead.
+impl<T> Drop for SinglyLinkedList<T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
 }
 
-#[allow(dead_code)]
-impl<'a, T: std::fmt::Debug + PartialEq> SinglyLinkedList<'a, T> {
+impl<T> SinglyLinkedList<T> {
     /// Create a new singly linked list
     /// 
     /// returns:
-    ///     SinglyLinkedList<'a, T> - A new singly linked list
-    fn new() -> Self {
-        SinglyLinkedList { head: None }
+    ///     SinglyLinkedList<T> - A new singly linked list
+    pub fn new() -> Self {
+        SinglyLinkedList { head: None, len: 0 }
     }
 
     /// Push a new node to the front of the list
     /// 
     /// args:
-    ///     data: &'a T - The data to be stored in the new node
-    fn push_front(&mut self, data: &'a T) {
+    ///     data: T - The data to be stored in the new node
+    pub fn push_front(&mut self, data: T) {
         let new_node = Node {
             data,
             next: self.head.take()
         };
 
         self.head = Some(Box::new(new_node));
+        self.len += 1;
     }
 
     /// Push a new node to the back of the list
     /// 
     /// args:
-    ///     data: &'a T - The data to be stored in the new node
-    fn push_back(&mut self, data: &'a T) {
+    ///     data: T - The data to be stored in the new node
+    pub fn push_back(&mut self, data: T) {
+        self.len += 1;
         if self.head.is_none() {
             self.head = Some(Box::new(
                 Node { 
@@ -63,25 +83,23 @@ impl<'a, T: std::fmt::Debug + PartialEq> SinglyLinkedList<'a, T> {
     /// Remove the first node from the list
     /// 
     /// returns:
-    ///     None - If the list is empty
-    fn pop_front(&mut self) {
-        if self.head.is_none() {
-            println!("Linked List is empty");
-            return;
-        }
-
-        self.head = self.head.take().unwrap().next;
+    ///     Option<T> - The data of the removed node, `None` if the list is empty
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.head.take().map(|node| {
+            self.head = node.next;
+            self.len -= 1;
+            node.data
+        })
     }
 
     /// Remove the last node from the list
     /// 
     /// returns:
-    ///     Option<&'a T> - The data of the removed node
-    fn pop_back(&mut self) -> Option<&'a T> {
+    ///     Option<T> - The data of the removed node
+    pub fn pop_back(&mut self) -> Option<T> {
         // If the list is empty, return None
-        if self.head.is_none() {
-            return None;
-        }
+        self.head.as_ref()?;
+        self.len -= 1;
         // If there's only one element
         if self.head.as_ref().unwrap().next.is_none() {
             // Take and return the data of the only node
@@ -100,24 +118,26 @@ impl<'a, T: std::fmt::Debug + PartialEq> SinglyLinkedList<'a, T> {
 
     /// Insert a new node at a specific index
     /// 
-    ///     args:
+    /// args:
+    ///     index: usize - The index to insert the new node at
+    ///     data: T - The data to be stored in the new node
     /// 
-    ///     index: u32 - The index to insert the new node at
-    ///     data: &'a T - The data to be stored in the new node
-    fn insert_at_index(&mut self, index: u32, data: &'a T) {
-        if index > self.get_length() {
-            println!("Index out of range");
-            return;
+    /// returns:
+    ///     Err(ListError::IndexOutOfBounds) - If `index` is past the end of the list
+    pub fn insert_at_index(&mut self, index: usize, data: T) -> Result<(), ListError> {
+        let len = self.len;
+        if index > len {
+            return Err(ListError::IndexOutOfBounds { index, len });
         }
 
         if index == 0 {
             self.push_front(data);
-            return;
+            return Ok(());
         }
 
-        if index == self.get_length() {
+        if index == len {
             self.push_back(data);
-            return;
+            return Ok(());
         }
 
         let mut index_counter = 0;
@@ -134,98 +154,299 @@ impl<'a, T: std::fmt::Debug + PartialEq> SinglyLinkedList<'a, T> {
         };
 
         curr.next = Some(Box::new(new_node));
+        self.len += 1;
+        Ok(())
     }
 
-    /// Get the length of the list
+    /// Get the length of the list in O(1)
     /// 
     /// returns:
-    ///     u32 - The length of the list
-    fn get_length(&self) -> u32 {
-        let mut counter: u32 = 0;
-        let mut curr = self.head.as_ref();
-        while let Some(value) = curr {
-            curr = value.next.as_ref();
-            counter += 1;
-        }
-
-        return counter;
+    ///     usize - The length of the list
+    pub fn len(&self) -> usize {
+        self.len
     }
 
     /// Find a node with a specific data
     /// 
     /// args:
-    ///     data: &'a T - The data to be searched for
+    ///     data: &T - The data to be searched for
     /// 
     /// returns:
-    ///     (bool, u32) - A tuple containing a boolean and the index of the node
-    fn find(&self, data: &'a T) -> (bool, u32)
+    ///     (bool, usize) - A tuple containing a boolean and the index of the node
+    pub fn find(&self, data: &T) -> (bool, usize)
     where
         T: PartialEq
     {
-        let mut curr = self.head.as_ref();
-        let mut index_counter: u32 = 0;
-        while let Some(value) = curr {
-            if value.data == data {
-                return (true, index_counter);
+        match self.iter().position(|value| value == data) {
+            Some(index) => (true, index),
+            None => (false, 0),
+        }
+    }
+
+    /// Find the index of the first node whose data matches a predicate
+    /// 
+    /// returns:
+    ///     Option<usize> - The index of the first match, `None` if nothing matches
+    pub fn position<F>(&self, predicate: F) -> Option<usize>
+    where
+        F: FnMut(&T) -> bool
+    {
+        self.iter().position(predicate)
+    }
+
+    /// Find the first data that matches a predicate
+    /// 
+    /// returns:
+    ///     Option<&T> - The first match, `None` if nothing matches
+    pub fn find_by<F>(&self, mut predicate: F) -> Option<&T>
+    where
+        F: FnMut(&T) -> bool
+    {
+        self.iter().find(|value| predicate(value))
+    }
+
+    /// Check if any node holds a specific data
+    pub fn contains(&self, data: &T) -> bool
+    where
+        T: PartialEq
+    {
+        self.iter().any(|value| value == data)
+    }
+
+    /// Keep only the nodes whose data matches a predicate, in a single pass
+    /// 
+    /// args:
+    ///     keep: FnMut(&T) -> bool - Returns false for the nodes to remove
+    pub fn retain<F>(&mut self, mut keep: F)
+    where
+        F: FnMut(&T) -> bool
+    {
+        let mut cursor = self.cursor_front_mut();
+        while let Some(data) = cursor.current() {
+            if keep(data) {
+                cursor.move_next();
+            } else {
+                cursor.remove_current();
             }
-            index_counter += 1;
-            curr = value.next.as_ref();
         }
+    }
 
-        return (false, 0);
+    /// Remove the first node holding a specific data
+    /// 
+    /// returns:
+    ///     Option<T> - The removed data, `None` if no node holds `data`
+    pub fn remove_first(&mut self, data: &T) -> Option<T>
+    where
+        T: PartialEq
+    {
+        let index = self.iter().position(|value| value == data)?;
+        self.remove_at(index).ok()
+    }
+
+    /// Remove every node holding a specific data
+    /// 
+    /// returns:
+    ///     usize - The number of nodes removed
+    pub fn remove_all(&mut self, data: &T) -> usize
+    where
+        T: PartialEq
+    {
+        let len = self.len;
+        self.retain(|value| value != data);
+        len - self.len
     }
 
     /// Insert a new node after a specific node
     /// 
     /// args:
-    ///     node: &'a T - The node to insert the new node after
+    ///     node: &T - The node to insert the new node after
+    /// 
+    ///    data: T - The data to be stored in the new node
     /// 
-    ///    data: &'a T - The data to be stored in the new node
-    fn insert_after(&mut self, node: &'a T, data: &'a T) {
+    /// returns:
+    ///     Err(ListError::Empty) - If the list is empty
+    ///     Err(ListError::NotFound) - If no node holds `node`
+    pub fn insert_after(&mut self, node: &T, data: T) -> Result<(), ListError>
+    where
+        T: PartialEq
+    {
         if self.head.is_none() {
-            println!("Linked List is empty");
-            return;
+            return Err(ListError::Empty);
         }
 
-        let mut curr = self.head.as_mut().unwrap();
-        while curr.data != node {
-            curr = curr.next.as_mut().unwrap();
+        let mut curr = self.head.as_mut();
+        while let Some(value) = curr {
+            if value.data == *node {
+                let new_node = Node {
+                    data,
+                    next: value.next.take()
+                };
+
+                value.next = Some(Box::new(new_node));
+                self.len += 1;
+                return Ok(());
+            }
+            curr = value.next.as_mut();
         }
-        
-        let new_node = Node {
-            data,
-            next: curr.next.take()
-        };
 
-        curr.next = Some(Box::new(new_node));
+        Err(ListError::NotFound)
     }
 
     /// Check if the list is empty
     /// 
     /// returns:
     ///     bool - True if the list is empty, false otherwise
-    fn is_empty(&self) -> bool {
+    pub fn is_empty(&self) -> bool {
         self.head.is_none()
     }
 
+    /// Remove every node from the list
+    /// 
+    /// Runs in a loop rather than recursively, so it is safe on lists of any length
+    pub fn clear(&mut self) {
+        let mut curr = self.head.take();
+        while let Some(mut boxed_node) = curr {
+            curr = boxed_node.next.take();
+        }
+        self.len = 0;
+    }
+
     /// Display the list
     /// 
     /// where:
     ///     T: std::fmt::Display - The type of the data in the list
-    fn display(&self)
+    pub fn display(&self)
     where 
         T: std::fmt::Display
     {
-        let mut curr = self.head.as_ref();
-        while let Some(value) = curr {
-            print!("{} -> ", value.data);
-            curr = value.next.as_ref();
+        println!("{}", self);
+    }
+
+    /// Get a reference to the data at a specific index
+    /// 
+    /// returns:
+    ///     Option<&T> - `None` if `index` is out of range
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.iter().nth(index)
+    }
+
+    /// Get a mutable reference to the data at a specific index
+    /// 
+    /// returns:
+    ///     Option<&mut T> - `None` if `index` is out of range
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.iter_mut().nth(index)
+    }
+
+    /// Overwrite the data at a specific index
+    /// 
+    /// returns:
+    ///     Ok(T) - The data that was replaced
+    ///     Err(ListError::IndexOutOfBounds) - If there is no node at `index`
+    pub fn set(&mut self, index: usize, data: T) -> Result<T, ListError> {
+        let len = self.len;
+        self.get_mut(index)
+            .map(|value| std::mem::replace(value, data))
+            .ok_or(ListError::IndexOutOfBounds { index, len })
+    }
+
+    /// Remove the node at a specific index
+    /// 
+    /// returns:
+    ///     Ok(T) - The data of the removed node
+    ///     Err(ListError::IndexOutOfBounds) - If there is no node at `index`
+    pub fn remove_at(&mut self, index: usize) -> Result<T, ListError> {
+        if index >= self.len {
+            return Err(ListError::IndexOutOfBounds { index, len: self.len });
+        }
+
+        if index == 0 {
+            return self.pop_front().ok_or(ListError::IndexOutOfBounds { index, len: self.len });
+        }
+
+        let mut curr = self.head.as_mut().unwrap();
+        for _ in 1..index {
+            curr = curr.next.as_mut().unwrap();
+        }
+
+        let removed = curr.next.take().unwrap();
+        curr.next = removed.next;
+        self.len -= 1;
+        Ok(removed.data)
+    }
+
+    /// Move every node of `other` to the back of this list, leaving `other` empty
+    /// 
+    /// O(n), the list keeps no tail pointer so the last node has to be found
+    /// 
+    /// args:
+    ///     other: &mut SinglyLinkedList<T> - The list to move the nodes from
+    pub fn append(&mut self, other: &mut Self) {
+        let mut tail = &mut self.head;
+        while let Some(node) = tail {
+            tail = &mut node.next;
+        }
+
+        *tail = other.head.take();
+        self.len += std::mem::take(&mut other.len);
+    }
+
+    /// Move every node of `other` to the front of this list
+    /// 
+    /// O(other.len()), only `other` has to be walked
+    /// 
+    /// args:
+    ///     other: SinglyLinkedList<T> - The list to put in front
+    pub fn prepend(&mut self, mut other: Self) {
+        other.append(self);
+        std::mem::swap(self, &mut other);
+    }
+
+    /// Split the list in two at a specific index
+    /// 
+    /// args:
+    ///     at: usize - The index of the first node of the returned list
+    /// 
+    /// returns:
+    ///     Ok(SinglyLinkedList<T>) - The nodes from `at` onwards
+    ///     Err(ListError::IndexOutOfBounds) - If `at` is greater than the length
+    pub fn split_off(&mut self, at: usize) -> Result<Self, ListError> {
+        if at > self.len {
+            return Err(ListError::IndexOutOfBounds { index: at, len: self.len });
+        }
+
+        if at == 0 {
+            return Ok(std::mem::take(self));
+        }
+
+        let mut curr = self.head.as_mut().unwrap();
+        for _ in 1..at {
+            curr = curr.next.as_mut().unwrap();
         }
-        println!("None");
+
+        let len = self.len - at;
+        self.len = at;
+        Ok(SinglyLinkedList {
+            head: curr.next.take(),
+            len
+        })
+    }
+
+    /// Move every node of `other` into this list at a specific index
+    /// 
+    /// After the splice the first node of `other` is at position `index`
+    /// 
+    /// returns:
+    ///     Err(ListError::IndexOutOfBounds) - If `index` is greater than the length
+    pub fn splice_at(&mut self, index: usize, mut other: Self) -> Result<(), ListError> {
+        let mut rest = self.split_off(index)?;
+        self.append(&mut other);
+        self.append(&mut rest);
+        Ok(())
     }
 
     /// Reverse the list
-    fn reverse(&mut self) {
+    pub fn reverse(&mut self) {
         let mut prev: Option<_> = None;
         let mut curr = self.head.take();
 
@@ -238,53 +459,1228 @@ impl<'a, T: std::fmt::Debug + PartialEq> SinglyLinkedList<'a, T> {
 
         self.head = prev;
     }
+
+    /// Sort the list in ascending order
+    /// 
+    /// Stable merge sort in O(n log n) that relinks the nodes, the data is never moved
+    pub fn sort(&mut self)
+    where
+        T: Ord
+    {
+        self.sort_by(T::cmp);
+    }
+
+    /// Sort the list with a comparator function, keeping equal elements in order
+    /// 
+    /// If `cmp` panics the list keeps every node, in an unspecified order
+    /// 
+    /// args:
+    ///     cmp: F - Returns how the first element is ordered relative to the second
+    pub fn sort_by<F>(&mut self, mut cmp: F)
+    where
+        F: FnMut(&T, &T) -> Ordering
+    {
+        // Bottom-up: the nodes are taken one at a time and merged like a
+        // binary counter, so `runs[i]` is empty or a sorted run of 2^i nodes
+        let mut guard = SortGuard::new(self);
+        while let Some(mut node) = guard.list.head.take() {
+            guard.list.head = node.next.take();
+            guard.right = Some(node);
+
+            let mut rank = 0;
+            while let Some(run) = guard.runs.get_mut(rank).and_then(Option::take) {
+                // The run holds earlier nodes than the carry, so it goes on the left
+                guard.left = Some(run);
+                guard.merge(&mut cmp);
+                guard.right = guard.merged.take();
+                rank += 1;
+            }
+            if rank == guard.runs.len() {
+                guard.runs.push(None);
+            }
+            guard.runs[rank] = guard.right.take();
+        }
+
+        // Fold the runs from the shortest one, the longer runs hold the earlier nodes
+        for rank in 0..guard.runs.len() {
+            if let Some(run) = guard.runs[rank].take() {
+                guard.right = guard.merged.take();
+                guard.left = Some(run);
+                guard.merge(&mut cmp);
+            }
+        }
+        // Dropping the guard links the sorted nodes back into the list
+    }
+
+    /// Sort the list by the key extracted from each element, keeping equal keys in order
+    /// 
+    /// args:
+    ///     key: F - Extracts the key to compare, called twice per comparison
+    pub fn sort_by_key<K, F>(&mut self, mut key: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K
+    {
+        self.sort_by(|a, b| key(a).cmp(&key(b)));
+    }
+
+    /// Insert a new node into a sorted list, after any nodes equal to it
+    /// 
+    /// args:
+    ///     data: T - The data to be stored in the new node
+    pub fn insert_sorted(&mut self, data: T)
+    where
+        T: Ord
+    {
+        let mut cursor = self.cursor_front_mut();
+        while cursor.current().is_some_and(|value| *value <= data) {
+            cursor.move_next();
+        }
+        cursor.insert_before(data);
+    }
+
+    /// Check if the list is sorted in ascending order
+    pub fn is_sorted(&self) -> bool
+    where
+        T: PartialOrd
+    {
+        self.iter().is_sorted()
+    }
+
+    /// Merge two sorted lists into one sorted list by relinking their nodes
+    /// 
+    /// O(a.len() + b.len()), on ties the node from `a` comes first
+    /// 
+    /// returns:
+    ///     SinglyLinkedList<T> - Every node of `a` and `b` in ascending order
+    pub fn merge_sorted(mut a: Self, mut b: Self) -> Self
+    where
+        T: Ord
+    {
+        a.len += std::mem::take(&mut b.len);
+        let mut guard = SortGuard::new(&mut a);
+        guard.left = guard.list.head.take();
+        guard.right = b.head.take();
+        guard.merge(&mut T::cmp);
+        drop(guard);
+        a
+    }
+
+    /// Remove consecutive repeated nodes, keeping the first of each run
+    /// 
+    /// On a sorted list this removes every duplicate
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq
+    {
+        self.dedup_by(|a, b| a == b);
+    }
+
+    /// Remove consecutive nodes that map to the same key, keeping the first of each run
+    /// 
+    /// args:
+    ///     key: F - Extracts the key to compare
+    pub fn dedup_by_key<K, F>(&mut self, mut key: F)
+    where
+        K: PartialEq,
+        F: FnMut(&mut T) -> K
+    {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
+
+    /// Remove consecutive nodes for which `same_bucket` returns true
+    /// 
+    /// args:
+    ///     same_bucket: F - Called with a node and the last node that was kept before it
+    pub fn dedup_by<F>(&mut self, mut same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool
+    {
+        let mut kept = match self.head.as_mut() {
+            Some(node) => node,
+            None => return,
+        };
+
+        while let Some(mut next) = kept.next.take() {
+            if same_bucket(&mut next.data, &mut kept.data) {
+                kept.next = next.next.take();
+                self.len -= 1;
+            } else {
+                kept = kept.next.insert(next);
+            }
+        }
+    }
+
+    /// Union of two sorted lists, an element in both is only kept once (from `self`)
+    /// 
+    /// Equal elements are paired up one to one, so repeated elements behave
+    /// like a multiset: `[2, 2]` and `[2]` give `[2, 2]`
+    /// 
+    /// O(self.len() + other.len()), the nodes are relinked, not copied
+    pub fn union(self, other: Self) -> Self
+    where
+        T: Ord
+    {
+        self.combine_sorted(other, true, true, true)
+    }
+
+    /// Intersection of two sorted lists, keeps the nodes of `self` that are also in `other`
+    /// 
+    /// O(self.len() + other.len()), the nodes are relinked, not copied
+    pub fn intersection(self, other: Self) -> Self
+    where
+        T: Ord
+    {
+        self.combine_sorted(other, false, true, false)
+    }
+
+    /// Difference of two sorted lists, keeps the nodes of `self` that are not in `other`
+    /// 
+    /// O(self.len() + other.len()), the nodes are relinked, not copied
+    pub fn difference(self, other: Self) -> Self
+    where
+        T: Ord
+    {
+        self.combine_sorted(other, true, false, false)
+    }
+
+    /// Walk two sorted lists side by side like a merge and decide which nodes to keep
+    /// 
+    /// args:
+    ///     keep_left: bool - Keep nodes only found in `self`
+    ///     keep_both: bool - Keep the node of `self` when both lists hold an equal element
+    ///     keep_right: bool - Keep nodes only found in `other`
+    fn combine_sorted(mut self, mut other: Self, keep_left: bool, keep_both: bool, keep_right: bool) -> Self
+    where
+        T: Ord
+    {
+        let mut left = self.head.take();
+        let mut right = other.head.take();
+        let mut head = None;
+        let mut tail = &mut head;
+        let mut len = 0;
+
+        loop {
+            let order = match (&left, &right) {
+                (Some(l), Some(r)) => l.data.cmp(&r.data),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => break,
+            };
+
+            let (source, keep) = match order {
+                Ordering::Less => (&mut left, keep_left),
+                Ordering::Greater => (&mut right, keep_right),
+                Ordering::Equal => {
+                    // The equal node from `other` is never kept
+                    let mut node = right.take().unwrap();
+                    right = node.next.take();
+                    (&mut left, keep_both)
+                }
+            };
+
+            let mut node = source.take().unwrap();
+            *source = node.next.take();
+            if keep {
+                tail = &mut tail.insert(node).next;
+                len += 1;
+            }
+        }
+
+        SinglyLinkedList { head, len }
+    }
+
+    /// Get a cursor pointing at the first node that can edit the list in place
+    /// 
+    /// An empty list gives a cursor at the "ghost" position, see [`CursorMut`]
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut {
+            list: self,
+            prev: None,
+            index: 0
+        }
+    }
+
+    /// Iterate over references to the data, front to back
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            next: self.head.as_deref(),
+            len: self.len
+        }
+    }
+
+    /// Iterate over mutable references to the data, front to back
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            next: self.head.as_deref_mut(),
+            len: self.len
+        }
+    }
+}
+
+/// Owns the chains of a sort or a merge while `cmp` runs
+/// 
+/// Dropping the guard links every chain back into the list, so a panic in
+/// `cmp` keeps all the nodes, only their order is unspecified. The unsorted
+/// nodes stay in the list's own head meanwhile, and `len` never changes.
+struct SortGuard<'a, T> {
+    list: &'a mut SinglyLinkedList<T>,
+    /// Sorted runs waiting to be merged
+    runs: Vec<Option<Box<Node<T>>>>,
+    left: Option<Box<Node<T>>>,
+    right: Option<Box<Node<T>>>,
+    merged: Option<Box<Node<T>>>,
+}
+
+impl<'a, T> SortGuard<'a, T> {
+    fn new(list: &'a mut SinglyLinkedList<T>) -> Self {
+        SortGuard { list, runs: Vec::new(), left: None, right: None, merged: None }
+    }
+
+    /// Merge `left` and `right` into the empty `merged`, taking from `left` first on ties
+    fn merge<F>(&mut self, cmp: &mut F)
+    where
+        F: FnMut(&T, &T) -> Ordering
+    {
+        let mut tail = &mut self.merged;
+
+        while let (Some(l), Some(r)) = (&self.left, &self.right) {
+            let take_left = cmp(&l.data, &r.data) != Ordering::Greater;
+            let source = if take_left { &mut self.left } else { &mut self.right };
+            let mut node = source.take().unwrap();
+            *source = node.next.take();
+            tail = &mut tail.insert(node).next;
+        }
+
+        // One side ran out, the rest of the other one is already in order
+        *tail = self.left.take().or(self.right.take());
+    }
+}
+
+impl<T> Drop for SortGuard<'_, T> {
+    fn drop(&mut self) {
+        let rest = self.list.head.take();
+        let chains = [self.merged.take(), self.left.take(), self.right.take()]
+            .into_iter()
+            .chain(self.runs.drain(..))
+            .chain([rest]);
+
+        // Move the nodes one by one, dropping a whole chain would recurse
+        let mut tail = &mut self.list.head;
+        for mut chain in chains {
+            while let Some(mut node) = chain {
+                chain = node.next.take();
+                tail = &mut tail.insert(node).next;
+            }
+        }
+    }
+}
+
+/// Cursor returned by [`SinglyLinkedList::cursor_front_mut`]
+/// 
+/// Modeled on `std::collections::linked_list::CursorMut`. The cursor points
+/// at a node, or at a "ghost" position between the last and the first node
+/// when it runs off either end.
+/// 
+/// The cursor keeps a pointer to the node before it, whose `next` link holds
+/// the node under the cursor, so the list stays fully linked while the cursor
+/// lives. Moving forward and editing around the cursor are O(1), but
+/// `move_prev` has to walk from the front again.
+pub struct CursorMut<'a, T> {
+    list: &'a mut SinglyLinkedList<T>,
+    // `None` while the node under the cursor is held by `list.head`. Nodes
+    // live in their own `Box` and are only freed once they have been
+    // unlinked, which never happens to `prev` while the cursor points past it.
+    prev: Option<NonNull<Node<T>>>,
+    // `list.len` at the ghost position
+    index: usize,
+}
+
+impl<T> CursorMut<'_, T> {
+    /// Get the index of the node under the cursor, `None` at the ghost position
+    pub fn index(&self) -> Option<usize> {
+        (self.index < self.list.len).then_some(self.index)
+    }
+
+    /// Get the data under the cursor, `None` at the ghost position
+    pub fn current(&mut self) -> Option<&mut T> {
+        self.link().as_mut().map(|node| &mut node.data)
+    }
+
+    /// Move to the next node
+    /// 
+    /// From the last node this moves to the ghost position, and from the ghost
+    /// position back to the first node.
+    pub fn move_next(&mut self) {
+        match self.link().as_deref_mut().map(NonNull::from) {
+            Some(node) => {
+                self.prev = Some(node);
+                self.index += 1;
+            }
+            None => {
+                self.prev = None;
+                self.index = 0;
+            }
+        }
+    }
+
+    /// Move to the previous node
+    /// 
+    /// O(index), the previous node is found by walking from the front. From
+    /// the first node this moves to the ghost position, and from the ghost
+    /// position to the last node.
+    pub fn move_prev(&mut self) {
+        let index = self.index.checked_sub(1).unwrap_or(self.list.len);
+        self.seek(index);
+    }
+
+    /// Insert a new node after the cursor
+    /// 
+    /// At the ghost position the node becomes the new front of the list
+    /// (O(n) to find the ghost position again).
+    pub fn insert_after(&mut self, data: T) {
+        match self.link().as_deref_mut() {
+            Some(node) => {
+                node.next = Some(Box::new(Node {
+                    data,
+                    next: node.next.take()
+                }));
+                self.list.len += 1;
+            }
+            None => {
+                self.list.push_front(data);
+                self.seek(self.list.len);
+            }
+        }
+    }
+
+    /// Insert a new node before the cursor
+    /// 
+    /// At the ghost position the node becomes the new back of the list
+    pub fn insert_before(&mut self, data: T) {
+        let link = self.link();
+        let next = link.take();
+        let node = link.insert(Box::new(Node { data, next }));
+        self.prev = Some(NonNull::from(&mut **node));
+        self.index += 1;
+        self.list.len += 1;
+    }
+
+    /// Remove the node under the cursor and move to the next one
+    /// 
+    /// returns:
+    ///     Option<T> - The removed data, `None` at the ghost position
+    pub fn remove_current(&mut self) -> Option<T> {
+        let link = self.link();
+        let mut node = link.take()?;
+        *link = node.next.take();
+        self.list.len -= 1;
+        Some(node.data)
+    }
+
+    /// Split the list in two after the cursor
+    /// 
+    /// returns:
+    ///     SinglyLinkedList<T> - Everything after the cursor, or the whole list
+    ///     when the cursor is at the ghost position
+    pub fn split_after(&mut self) -> SinglyLinkedList<T> {
+        match self.link().as_deref_mut() {
+            Some(node) => {
+                let head = node.next.take();
+                let len = self.list.len - self.index - 1;
+                self.list.len -= len;
+                SinglyLinkedList { head, len }
+            }
+            None => {
+                self.prev = None;
+                self.index = 0;
+                std::mem::take(self.list)
+            }
+        }
+    }
+
+    /// The link holding the node under the cursor, at the ghost position
+    /// the empty link after the last node
+    fn link(&mut self) -> &mut Option<Box<Node<T>>> {
+        match self.prev {
+            // SAFETY: `prev` is a live node of the list, see `CursorMut`, and
+            // the cursor borrows the list mutably
+            Some(prev) => unsafe { &mut (*prev.as_ptr()).next },
+            None => &mut self.list.head,
+        }
+    }
+
+    /// Move to the node at `index` by walking from the front, `len()` is the
+    /// ghost position
+    fn seek(&mut self, index: usize) {
+        self.prev = None;
+        for _ in 0..index {
+            let node = self.link().as_deref_mut().expect("index is in range");
+            self.prev = Some(NonNull::from(node));
+        }
+        self.index = index;
+    }
+}
+
+/// Borrowing iterator returned by [`SinglyLinkedList::iter`]
+pub struct Iter<'a, T> {
+    next: Option<&'a Node<T>>,
+    len: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|node| {
+            self.next = node.next.as_deref();
+            self.len -= 1;
+            &node.data
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+// Written out because deriving it would require `T: Clone`
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Iter {
+            next: self.next,
+            len: self.len
+        }
+    }
+}
+
+/// Mutable iterator returned by [`SinglyLinkedList::iter_mut`]
+pub struct IterMut<'a, T> {
+    next: Option<&'a mut Node<T>>,
+    len: usize,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.take().map(|node| {
+            self.next = node.next.as_deref_mut();
+            self.len -= 1;
+            &mut node.data
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+/// Consuming iterator returned by [`SinglyLinkedList::into_iter`]
+pub struct IntoIter<T>(SinglyLinkedList<T>);
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len, Some(self.0.len))
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> IntoIterator for SinglyLinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a SinglyLinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut SinglyLinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T> Index<usize> for SinglyLinkedList<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        let len = self.len;
+        self.get(index)
+            .unwrap_or_else(|| panic!("{}", ListError::IndexOutOfBounds { index, len }))
+    }
+}
+
+impl<T> IndexMut<usize> for SinglyLinkedList<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let len = self.len;
+        self.get_mut(index)
+            .unwrap_or_else(|| panic!("{}", ListError::IndexOutOfBounds { index, len }))
+    }
+}
+
+impl<T> Extend<T> for SinglyLinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        // Walk to the empty `next` slot once instead of once per element
+        let mut tail = &mut self.head;
+        while let Some(node) = tail {
+            tail = &mut node.next;
+        }
+
+        for data in iter {
+            let node = tail.insert(Box::new(Node { data, next: None }));
+            tail = &mut node.next;
+            self.len += 1;
+        }
+    }
+}
+
+impl<T> FromIterator<T> for SinglyLinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T: Clone> Clone for SinglyLinkedList<T> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: PartialEq> PartialEq for SinglyLinkedList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for SinglyLinkedList<T> {}
+
+impl<T: Hash> Hash for SinglyLinkedList<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        for data in self.iter() {
+            data.hash(state);
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for SinglyLinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for SinglyLinkedList<T> {
+    /// Renders the list as `a -> b -> None`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for data in self.iter() {
+            write!(f, "{} -> ", data)?;
+        }
+        write!(f, "None")
+    }
 }
 
-#[allow(dead_code)]
-/// Run the program
-pub fn run() {
-    let mut singly_ll = SinglyLinkedList::new();
-    singly_ll.push_front(&10);
-    singly_ll.push_front(&20);
-    singly_ll.push_front(&30);
+#[cfg(test)]
+mod tests {
+    use std::panic::{self, AssertUnwindSafe};
+
+    use crate::linked_list::test_utils;
+    use super::*;
+
+    fn values<T: Clone>(list: &SinglyLinkedList<T>) -> Vec<T> {
+        list.iter().cloned().collect()
+    }
+
+    #[test]
+    fn cursor_move_prev_wraps_through_the_ghost() {
+        let mut list: SinglyLinkedList<_> = (1..=3).collect();
+        let mut cursor = list.cursor_front_mut();
+
+        cursor.move_prev();
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.index(), None);
+        cursor.move_prev();
+        assert_eq!(cursor.current(), Some(&mut 3));
+        assert_eq!(cursor.index(), Some(2));
+        cursor.move_next();
+        assert_eq!(cursor.index(), None);
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&mut 1));
+        assert_eq!(cursor.index(), Some(0));
+
+        assert_eq!(values(&list), [1, 2, 3]);
+    }
+
+    #[test]
+    fn cursor_inserts_at_the_ghost_go_to_either_end() {
+        let mut list: SinglyLinkedList<_> = (1..=2).collect();
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.index(), None);
+
+        cursor.insert_after(0);
+        cursor.insert_before(3);
+        assert_eq!(cursor.current(), None);
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&mut 0));
+        assert_eq!(cursor.index(), Some(0));
+
+        assert_eq!(values(&list), [0, 1, 2, 3]);
+        assert_eq!(list.len(), 4);
+    }
+
+    #[test]
+    fn cursor_remove_current_at_the_tail_moves_to_the_ghost() {
+        let mut list: SinglyLinkedList<_> = (1..=3).collect();
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        cursor.move_next();
+
+        assert_eq!(cursor.remove_current(), Some(3));
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.index(), None);
+        assert_eq!(cursor.remove_current(), None);
+        cursor.move_prev();
+        assert_eq!(cursor.current(), Some(&mut 2));
+        assert_eq!(cursor.index(), Some(1));
+
+        assert_eq!(values(&list), [1, 2]);
+        assert_eq!(list.len(), 2);
+    }
+
+    #[test]
+    fn cursor_split_after() {
+        let mut list: SinglyLinkedList<_> = (1..=5).collect();
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+
+        let tail = cursor.split_after();
+        assert_eq!(values(&tail), [3, 4, 5]);
+        assert_eq!(tail.len(), 3);
+        assert_eq!(cursor.index(), Some(1));
+        assert_eq!(values(&list), [1, 2]);
+        assert_eq!(list.len(), 2);
+
+        // At the ghost position, after moving past every node
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        cursor.move_next();
+        let everything = cursor.split_after();
+        assert_eq!(cursor.index(), None);
+        cursor.move_next();
+        assert_eq!(cursor.current(), None);
+        assert_eq!(values(&everything), [1, 2]);
+        assert_eq!(everything.len(), 2);
+        assert!(list.is_empty());
+        assert_eq!(list.len(), 0);
+    }
+
+    #[test]
+    fn cursor_index_follows_edits() {
+        let mut list: SinglyLinkedList<_> = [10, 20, 30].into_iter().collect();
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        assert_eq!(cursor.index(), Some(1));
+
+        cursor.insert_before(15);
+        assert_eq!(cursor.index(), Some(2));
+        assert_eq!(cursor.current(), Some(&mut 20));
+        cursor.insert_after(25);
+        assert_eq!(cursor.index(), Some(2));
+        assert_eq!(cursor.remove_current(), Some(20));
+        assert_eq!(cursor.index(), Some(2));
+        assert_eq!(cursor.current(), Some(&mut 25));
+        cursor.move_prev();
+        assert_eq!(cursor.index(), Some(1));
+        assert_eq!(cursor.current(), Some(&mut 15));
+
+        assert_eq!(values(&list), [10, 15, 25, 30]);
+        assert_eq!(list.len(), 4);
+    }
+
+    #[test]
+    fn cursor_never_unlinks_the_list() {
+        // Nothing to restore on drop, so leaking a cursor cannot break the list
+        assert!(!std::mem::needs_drop::<CursorMut<'_, String>>());
+
+        let mut list: SinglyLinkedList<_> = (1..=5).collect();
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        cursor.move_next();
+        cursor.insert_before(9);
+
+        assert_eq!(list.len(), 6);
+        assert_eq!(values(&list), [1, 2, 9, 3, 4, 5]);
+        assert_eq!(list.remove_at(5), Ok(5));
+        assert_eq!(values(&list), [1, 2, 9, 3, 4]);
+    }
+
+    #[test]
+    fn cursor_matches_a_vec_model() {
+        let mut list: SinglyLinkedList<_> = (0..4).collect();
+        let mut model: Vec<_> = (0..4).collect();
+        // `model.len()` is the ghost position
+        let mut position = 0;
+        let mut cursor = list.cursor_front_mut();
+
+        let mut seed = 0x2545_f491_u32;
+        for step in 10..510 {
+            seed ^= seed << 13;
+            seed ^= seed >> 17;
+            seed ^= seed << 5;
+
+            match seed % 6 {
+                0 => {
+                    cursor.move_next();
+                    position = if position == model.len() { 0 } else { position + 1 };
+                }
+                1 => {
+                    cursor.move_prev();
+                    position = if position == 0 { model.len() } else { position - 1 };
+                }
+                2 => {
+                    cursor.insert_after(step);
+                    if position == model.len() {
+                        model.insert(0, step);
+                        position += 1;
+                    } else {
+                        model.insert(position + 1, step);
+                    }
+                }
+                3 => {
+                    cursor.insert_before(step);
+                    model.insert(position, step);
+                    position += 1;
+                }
+                4 => {
+                    let removed = cursor.remove_current();
+                    assert_eq!(removed, (position < model.len()).then(|| model.remove(position)));
+                }
+                _ if model.len() > 8 => {
+                    let back = cursor.split_after();
+                    let back_model = if position == model.len() {
+                        position = 0;
+                        std::mem::take(&mut model)
+                    } else {
+                        model.split_off(position + 1)
+                    };
+                    assert_eq!(back.len(), back_model.len());
+                    assert_eq!(values(&back), back_model);
+                }
+                _ => {}
+            }
+
+            assert_eq!(cursor.index(), (position < model.len()).then_some(position), "step {}", step);
+            assert_eq!(cursor.current().copied(), model.get(position).copied(), "step {}", step);
+        }
+
+        assert_eq!(list.len(), model.len());
+        assert_eq!(values(&list), model);
+    }
+
+    #[test]
+    fn sort_by_keeps_every_node_when_cmp_panics() {
+        // Sorting 10 nodes takes fewer than 30 comparisons, so this panics in
+        // every phase of the sort and finally not at all
+        for limit in 0..30 {
+            let mut list: SinglyLinkedList<_> = [3, 7, 0, 9, 1, 8, 2, 6, 4, 5].into_iter().collect();
+            let mut calls = 0;
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                list.sort_by(|a, b| {
+                    calls += 1;
+                    assert!(calls <= limit, "comparator gave up");
+                    a.cmp(b)
+                });
+            }));
+
+            assert_eq!(result.is_err(), calls > limit);
+            assert_eq!(list.len(), 10);
+            let mut sorted = values(&list);
+            sorted.sort();
+            assert_eq!(sorted, (0..10).collect::<Vec<_>>(), "limit {}", limit);
+            assert!(result.is_err() || list.is_sorted());
+
+            list.push_back(10);
+            assert_eq!(list.remove_at(10), Ok(10));
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore = "millions of nodes are too slow under Miri")]
+    fn sort_by_unwinds_long_runs_without_recursing() {
+        const LEN: usize = 1_000_000;
+
+        let mut list: SinglyLinkedList<_> = (0..LEN).rev().collect();
+        let mut calls = 0;
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            list.sort_by(|a, b| {
+                calls += 1;
+                assert!(calls <= 5 * LEN, "comparator gave up");
+                a.cmp(b)
+            });
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(list.len(), LEN);
+        assert_eq!(list.iter().count(), LEN);
+    }
+
+    #[test]
+    fn merge_sorted_keeps_every_node_when_cmp_panics() {
+        /// Ordered by value, but comparing against `PANIC` panics
+        #[derive(Debug, PartialEq, Eq)]
+        struct Touchy(i32);
+
+        const PANIC: i32 = 4;
+
+        impl PartialOrd for Touchy {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for Touchy {
+            fn cmp(&self, other: &Self) -> Ordering {
+                assert!(self.0 != PANIC && other.0 != PANIC, "comparator gave up");
+                self.0.cmp(&other.0)
+            }
+        }
+
+        let token = std::rc::Rc::new(());
+        let a: SinglyLinkedList<_> = [1, 3, 5].into_iter().map(|n| (Touchy(n), token.clone())).collect();
+        let b: SinglyLinkedList<_> = [2, PANIC, 6].into_iter().map(|n| (Touchy(n), token.clone())).collect();
+        let result = panic::catch_unwind(AssertUnwindSafe(|| SinglyLinkedList::merge_sorted(a, b)));
+
+        assert!(result.is_err());
+        assert_eq!(std::rc::Rc::strong_count(&token), 1);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore = "millions of nodes are too slow under Miri")]
+    fn long_lists_are_freed_without_recursing() {
+        // A recursive drop of this many nodes overflows the test thread's stack
+        const LEN: usize = 3_000_000;
+
+        drop((0..LEN).collect::<SinglyLinkedList<_>>());
+
+        let mut list: SinglyLinkedList<_> = (0..LEN).collect();
+        list.clear();
+        assert!(list.is_empty());
+        assert_eq!(list.len(), 0);
+
+        let mut iter = (0..LEN).collect::<SinglyLinkedList<_>>().into_iter();
+        assert_eq!(iter.nth(LEN / 2), Some(LEN / 2));
+        assert_eq!(iter.len(), LEN / 2 - 1);
+        drop(iter);
+    }
+
+    #[test]
+    fn get_set_and_index_mut_reach_every_node() {
+        let mut list: SinglyLinkedList<_> = (1..=4).collect();
+        assert_eq!(list.get(0), Some(&1));
+        assert_eq!(list.get(3), Some(&4));
+        assert_eq!(list.get(4), None);
+
+        assert_eq!(list.set(0, 10), Ok(1));
+        assert_eq!(list.set(3, 40), Ok(4));
+        assert_eq!(list.set(4, 50), Err(ListError::IndexOutOfBounds { index: 4, len: 4 }));
+        *list.get_mut(1).unwrap() += 18;
+        assert_eq!(list.get_mut(4), None);
+        list[2] = 30;
+
+        assert_eq!(values(&list), [10, 20, 30, 40]);
+        assert_eq!((list[0], list[3]), (10, 40));
+        assert_eq!(list.len(), 4);
+
+        let mut empty = SinglyLinkedList::<i32>::new();
+        assert_eq!(empty.get_mut(0), None);
+        assert_eq!(empty.set(0, 1), Err(ListError::IndexOutOfBounds { index: 0, len: 0 }));
+    }
+
+    #[test]
+    #[should_panic(expected = "index 3 out of range for list of length 3")]
+    fn index_past_the_end_panics() {
+        let list: SinglyLinkedList<_> = (1..=3).collect();
+        let _ = list[3];
+    }
+
+    #[test]
+    #[should_panic(expected = "index 0 out of range for list of length 0")]
+    fn index_mut_on_an_empty_list_panics() {
+        let mut list = SinglyLinkedList::new();
+        list[0] = 1;
+    }
+
+    /// Compare the list with `expected`, including its len
+    fn assert_values(list: &SinglyLinkedList<i32>, expected: &[i32]) {
+        assert_eq!(values(list), expected);
+        assert_eq!(list.len(), expected.len());
+    }
+
+    #[test]
+    fn find_by_and_contains() {
+        let list: SinglyLinkedList<_> = [3, 8, 5, 8].into_iter().collect();
+        assert_eq!(list.find_by(|&value| value > 4), Some(&8));
+        assert_eq!(list.find_by(|&value| value == 3), Some(&3));
+        assert_eq!(list.find_by(|&value| value == 5), Some(&5));
+        assert_eq!(list.find_by(|&value| value > 8), None);
+        assert!(list.contains(&3));
+        assert!(list.contains(&8));
+        assert!(!list.contains(&4));
+
+        let empty = SinglyLinkedList::<i32>::new();
+        assert_eq!(empty.find_by(|_| true), None);
+        assert!(!empty.contains(&0));
+    }
 
-    singly_ll.display();
+    #[test]
+    fn remove_first_takes_only_the_first_match() {
+        let mut list: SinglyLinkedList<_> = [1, 2, 2, 3, 1].into_iter().collect();
+        assert_eq!(list.remove_first(&4), None);
+        assert_values(&list, &[1, 2, 2, 3, 1]);
+        assert_eq!(list.remove_first(&1), Some(1));
+        assert_values(&list, &[2, 2, 3, 1]);
+        assert_eq!(list.remove_first(&2), Some(2));
+        assert_values(&list, &[2, 3, 1]);
+        assert_eq!(list.remove_first(&1), Some(1));
+        assert_values(&list, &[2, 3]);
+        assert_eq!(list.remove_first(&3), Some(3));
+        assert_eq!(list.remove_first(&2), Some(2));
+        assert_values(&list, &[]);
+        assert_eq!(list.remove_first(&2), None);
+    }
+
+    #[test]
+    fn remove_all_at_the_head_tail_and_in_runs() {
+        let mut list: SinglyLinkedList<_> = [1, 1, 2, 1, 1, 3, 1].into_iter().collect();
+        assert_eq!(list.remove_all(&4), 0);
+        assert_values(&list, &[1, 1, 2, 1, 1, 3, 1]);
+        assert_eq!(list.remove_all(&1), 5);
+        assert_values(&list, &[2, 3]);
+        // The list still works at both ends afterwards
+        list.push_back(4);
+        list.push_front(0);
+        assert_values(&list, &[0, 2, 3, 4]);
+
+        let mut same: SinglyLinkedList<_> = [7, 7, 7].into_iter().collect();
+        assert_eq!(same.remove_all(&7), 3);
+        assert_values(&same, &[]);
+        assert_eq!(same.remove_all(&7), 0);
+    }
 
-    singly_ll.push_back(&50);
-    singly_ll.push_back(&80);
+    #[test]
+    fn retain_keeps_the_matching_nodes_in_order() {
+        let mut list: SinglyLinkedList<_> = (0..10).collect();
+        let mut seen = Vec::new();
+        list.retain(|&value| {
+            seen.push(value);
+            value % 3 != 0
+        });
+        // Every node is visited once, in order
+        assert_eq!(seen, (0..10).collect::<Vec<_>>());
+        assert_values(&list, &[1, 2, 4, 5, 7, 8]);
 
-    singly_ll.display(); 
+        list.retain(|_| true);
+        assert_values(&list, &[1, 2, 4, 5, 7, 8]);
+        list.retain(|_| false);
+        assert_values(&list, &[]);
+        list.retain(|_| false);
+        list.push_back(1);
+        assert_values(&list, &[1]);
+    }
 
-    singly_ll.pop_front();
-    singly_ll.display();
+    /// Ordered by `key` only, so `tag` shows which list an equal element came from
+    #[derive(Debug, Clone, Copy)]
+    struct Tagged {
+        key: i32,
+        tag: char,
+    }
 
-    if let Some(last_value) = singly_ll.pop_back() {
-        println!("Popped Value: {}", last_value);
+    impl PartialEq for Tagged {
+        fn eq(&self, other: &Self) -> bool {
+            self.key == other.key
+        }
     }
-    singly_ll.display();
 
-    singly_ll.insert_at_index(2, &100);
-    singly_ll.display();
+    impl Eq for Tagged {}
 
-    singly_ll.insert_after(&20, &100);
-    singly_ll.display();
+    impl PartialOrd for Tagged {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
 
-    singly_ll.insert_after(&20, &500);
-    singly_ll.display();
+    impl Ord for Tagged {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.key.cmp(&other.key)
+        }
+    }
 
-    let (found, index) = singly_ll.find(&50);
-    if found {
-        println!("Found at index: {}", index);
-    } else {
-        println!("Not found");
+    fn tagged(keys: &[i32], tag: char) -> SinglyLinkedList<Tagged> {
+        keys.iter().map(|&key| Tagged { key, tag }).collect()
     }
-    
-    if singly_ll.is_empty() {
-        println!("Linked List is empty");
-    } else {
-        println!("Linked List is not empty");
+
+    fn tags(list: &SinglyLinkedList<Tagged>) -> Vec<(i32, char)> {
+        list.iter().map(|item| (item.key, item.tag)).collect()
     }
 
-    singly_ll.reverse();
-    singly_ll.display();
-}
\ No newline at end of file
+    fn list_of(values: &[i32]) -> SinglyLinkedList<i32> {
+        values.iter().copied().collect()
+    }
+
+    #[test]
+    fn merge_sorted_interleaves_and_takes_ties_from_a_first() {
+        let merged = SinglyLinkedList::merge_sorted(tagged(&[1, 2, 2, 5], 'a'), tagged(&[2, 3, 5, 6], 'b'));
+        assert_eq!(
+            tags(&merged),
+            [(1, 'a'), (2, 'a'), (2, 'a'), (2, 'b'), (3, 'b'), (5, 'a'), (5, 'b'), (6, 'b')]
+        );
+        assert_eq!(merged.len(), 8);
+
+        // Disjoint ranges are joined end to end, whichever side comes first
+        assert_values(&SinglyLinkedList::merge_sorted(list_of(&[1, 2]), list_of(&[3, 4])), &[1, 2, 3, 4]);
+        assert_values(&SinglyLinkedList::merge_sorted(list_of(&[3, 4]), list_of(&[1, 2])), &[1, 2, 3, 4]);
+
+        assert_values(&SinglyLinkedList::merge_sorted(list_of(&[]), list_of(&[1, 2])), &[1, 2]);
+        assert_values(&SinglyLinkedList::merge_sorted(list_of(&[1, 2]), list_of(&[])), &[1, 2]);
+        assert_values(&SinglyLinkedList::merge_sorted(list_of(&[]), list_of(&[])), &[]);
+
+        let mut merged = SinglyLinkedList::merge_sorted(list_of(&[7, 7]), list_of(&[7, 7, 7]));
+        assert_values(&merged, &[7, 7, 7, 7, 7]);
+        merged.push_back(8);
+        assert_values(&merged, &[7, 7, 7, 7, 7, 8]);
+    }
+
+    #[test]
+    fn dedup_removes_consecutive_repeats_only() {
+        let mut list = list_of(&[1, 1, 1, 2, 3, 3, 1, 1]);
+        list.dedup();
+        assert_values(&list, &[1, 2, 3, 1]);
+
+        let mut list = list_of(&[4, 4, 4, 4]);
+        list.dedup();
+        assert_values(&list, &[4]);
+        list.push_back(5);
+        assert_values(&list, &[4, 5]);
+
+        let mut list = list_of(&[1, 2, 3]);
+        list.dedup();
+        assert_values(&list, &[1, 2, 3]);
+
+        let mut list = list_of(&[]);
+        list.dedup();
+        assert_values(&list, &[]);
+    }
+
+    #[test]
+    fn dedup_by_compares_with_the_last_kept_node() {
+        let mut list = list_of(&[1, 2, 3, 10, 11, 12, 20]);
+        let mut calls = Vec::new();
+        list.dedup_by(|next, kept| {
+            calls.push((*next, *kept));
+            *next - *kept < 5
+        });
+
+        // Every node is compared with the first node of its run, not its neighbour
+        assert_eq!(calls, [(2, 1), (3, 1), (10, 1), (11, 10), (12, 10), (20, 10)]);
+        assert_values(&list, &[1, 10, 20]);
+
+        let mut list = list_of(&[5, 5]);
+        list.dedup_by(|_, _| false);
+        assert_values(&list, &[5, 5]);
+        list.dedup_by(|_, _| true);
+        assert_values(&list, &[5]);
+    }
+
+    #[test]
+    fn dedup_by_key_keeps_the_first_of_each_run() {
+        let mut list = list_of(&[1, -1, 1, 2, -2, 3, -1]);
+        list.dedup_by_key(|value| value.abs());
+        assert_values(&list, &[1, 2, 3, -1]);
+
+        let mut list = list_of(&[-3, 3, -3, 3]);
+        list.dedup_by_key(|value| value.abs());
+        assert_values(&list, &[-3]);
+
+        let mut list = list_of(&[]);
+        list.dedup_by_key(|value| *value);
+        assert_values(&list, &[]);
+    }
+
+    #[test]
+    fn union_pairs_up_equal_elements() {
+        assert_values(&list_of(&[2, 2]).union(list_of(&[2])), &[2, 2]);
+        assert_values(&list_of(&[2]).union(list_of(&[2, 2])), &[2, 2]);
+        assert_values(&list_of(&[1, 3, 5]).union(list_of(&[2, 4])), &[1, 2, 3, 4, 5]);
+        assert_values(&list_of(&[1, 2, 3]).union(list_of(&[1, 2, 3])), &[1, 2, 3]);
+        assert_values(&list_of(&[1, 1, 2, 2, 2]).union(list_of(&[1, 2, 2, 3, 3])), &[1, 1, 2, 2, 2, 3, 3]);
+        assert_values(&list_of(&[]).union(list_of(&[1, 2])), &[1, 2]);
+        assert_values(&list_of(&[1, 2]).union(list_of(&[])), &[1, 2]);
+        assert_values(&list_of(&[]).union(list_of(&[])), &[]);
+
+        // An element in both lists is the node from `self`
+        let union = tagged(&[1, 2, 4], 'a').union(tagged(&[2, 3, 4, 4], 'b'));
+        assert_eq!(tags(&union), [(1, 'a'), (2, 'a'), (3, 'b'), (4, 'a'), (4, 'b')]);
+        assert_eq!(union.len(), 5);
+    }
+
+    #[test]
+    fn intersection_keeps_the_shared_nodes_of_self() {
+        assert_values(&list_of(&[2, 2]).intersection(list_of(&[2])), &[2]);
+        assert_values(&list_of(&[1, 1, 2, 2, 2, 3]).intersection(list_of(&[1, 2, 2, 4])), &[1, 2, 2]);
+        assert_values(&list_of(&[1, 3, 5]).intersection(list_of(&[2, 4])), &[]);
+        assert_values(&list_of(&[1, 2, 3]).intersection(list_of(&[1, 2, 3])), &[1, 2, 3]);
+        assert_values(&list_of(&[]).intersection(list_of(&[1, 2])), &[]);
+        assert_values(&list_of(&[1, 2]).intersection(list_of(&[])), &[]);
+
+        let intersection = tagged(&[1, 2, 4], 'a').intersection(tagged(&[2, 3, 4], 'b'));
+        assert_eq!(tags(&intersection), [(2, 'a'), (4, 'a')]);
+        assert_eq!(intersection.len(), 2);
+    }
+
+    #[test]
+    fn difference_removes_one_node_per_match() {
+        assert_values(&list_of(&[2, 2]).difference(list_of(&[2])), &[2]);
+        assert_values(&list_of(&[1, 1, 2, 2, 2, 3]).difference(list_of(&[1, 2, 2, 4])), &[1, 2, 3]);
+        assert_values(&list_of(&[1, 3, 5]).difference(list_of(&[2, 4])), &[1, 3, 5]);
+        assert_values(&list_of(&[1, 2, 3]).difference(list_of(&[1, 2, 3])), &[]);
+        assert_values(&list_of(&[]).difference(list_of(&[1, 2])), &[]);
+        assert_values(&list_of(&[1, 2]).difference(list_of(&[])), &[1, 2]);
+
+        let mut difference = list_of(&[4, 4, 4]).difference(list_of(&[4]));
+        assert_values(&difference, &[4, 4]);
+        difference.push_back(5);
+        assert_values(&difference, &[4, 4, 5]);
+    }
+
+    #[test]
+    fn std_traits() {
+        test_utils::check_std_traits::<SinglyLinkedList<i32>>("1 -> 2 -> 3 -> None", "None");
+    }
+}
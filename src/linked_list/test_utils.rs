@@ -0,0 +1,155 @@
+//! Checks shared by the tests of every list type
+
+use std::{fmt, hash::{DefaultHasher, Hash, Hasher}};
+
+fn hash_of<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Check `Display`, `Debug`, `Clone`, `Eq` and `Hash` of a list type
+/// 
+/// args:
+/// * `display`: how the list `[1, 2, 3]` is rendered
+/// * `empty_display`: how an empty list is rendered
+pub(crate) fn check_std_traits<L>(display: &str, empty_display: &str)
+where
+    L: Default + FromIterator<i32> + Extend<i32> + Clone + Eq + Hash + fmt::Display + fmt::Debug
+{
+    let list: L = (1..=3).collect();
+    assert_eq!(list.to_string(), display);
+    assert_eq!(format!("{:?}", list), "[1, 2, 3]");
+    assert_eq!(L::default().to_string(), empty_display);
+    assert_eq!(format!("{:?}", L::default()), "[]");
+
+    // Equal lists built in different ways hash the same
+    let mut built = L::default();
+    built.extend([1, 2]);
+    built.extend([3]);
+    assert_eq!(built, list);
+    assert_eq!(hash_of(&built), hash_of(&list));
+    assert_eq!(L::default(), (0..0).collect());
+    assert_eq!(hash_of(&L::default()), hash_of(&(0..0).collect::<L>()));
+
+    // A clone is equal but does not share nodes with the original
+    let mut clone = list.clone();
+    assert_eq!(clone, list);
+    assert_eq!(hash_of(&clone), hash_of(&list));
+    clone.extend([4]);
+    assert_ne!(clone, list);
+    assert_eq!(list.to_string(), display);
+
+    // A prefix, a different element or another order is not equal
+    for other in [vec![1, 2], vec![1, 2, 4], vec![3, 2, 1], vec![1, 2, 3, 3]] {
+        let other: L = other.into_iter().collect();
+        assert_ne!(other, list);
+        assert_ne!(hash_of(&other), hash_of(&list));
+    }
+}
+
+/// Generate a `leaks` test module for a list type, counting the live elements
+/// through the strong count of an `Rc` token
+/// 
+/// Every list gets tests for the pops, `clear`, `drop` and `into_iter`. The
+/// names after the list type add tests for the operations only some lists
+/// have: `split_off`, `remove_at` and `splice`.
+macro_rules! leak_tests {
+    ($list:ident $(, $extra:ident)*) => {
+        mod leaks {
+            use std::rc::Rc;
+
+            use super::$list;
+
+            /// A list holding `len` clones of `token`, so its strong count tracks the live nodes
+            fn counted(token: &Rc<()>, len: usize) -> $list<Rc<()>> {
+                (0..len).map(|_| Rc::clone(token)).collect()
+            }
+
+            #[test]
+            fn pops_free_their_nodes() {
+                let token = Rc::new(());
+                let mut list = counted(&token, 4);
+                assert!(list.pop_front().is_some());
+                assert!(list.pop_back().is_some());
+                assert_eq!(Rc::strong_count(&token), 3);
+
+                while list.pop_back().is_some() {}
+                assert_eq!(Rc::strong_count(&token), 1);
+            }
+
+            #[test]
+            fn clear_frees_every_node() {
+                let token = Rc::new(());
+                let mut list = counted(&token, 5);
+                list.clear();
+                assert_eq!(Rc::strong_count(&token), 1);
+                assert!(list.is_empty());
+
+                list.extend(counted(&token, 2));
+                assert_eq!(Rc::strong_count(&token), 3);
+            }
+
+            #[test]
+            fn drop_frees_every_node() {
+                let token = Rc::new(());
+                drop(counted(&token, 5));
+                assert_eq!(Rc::strong_count(&token), 1);
+            }
+
+            #[test]
+            fn into_iter_frees_taken_and_remaining_nodes() {
+                let token = Rc::new(());
+                let mut iter = counted(&token, 5).into_iter();
+                assert!(iter.next().is_some());
+                assert!(iter.next().is_some());
+                assert_eq!(Rc::strong_count(&token), 4);
+
+                drop(iter);
+                assert_eq!(Rc::strong_count(&token), 1);
+            }
+
+            $($crate::linked_list::test_utils::leak_tests!(@ $extra);)*
+        }
+    };
+    (@ split_off) => {
+        #[test]
+        fn split_off_frees_both_halves() {
+            let token = Rc::new(());
+            let mut list = counted(&token, 6);
+            let tail = list.split_off(2).expect("2 is in range");
+            assert_eq!(Rc::strong_count(&token), 7);
+
+            drop(list);
+            assert_eq!(Rc::strong_count(&token), 5);
+            drop(tail);
+            assert_eq!(Rc::strong_count(&token), 1);
+        }
+    };
+    (@ remove_at) => {
+        #[test]
+        fn remove_at_frees_the_node() {
+            let token = Rc::new(());
+            let mut list = counted(&token, 3);
+            assert!(list.remove_at(1).is_ok());
+            assert_eq!(Rc::strong_count(&token), 3);
+
+            drop(list);
+            assert_eq!(Rc::strong_count(&token), 1);
+        }
+    };
+    (@ splice) => {
+        #[test]
+        fn splice_frees_every_node_on_drop() {
+            let token = Rc::new(());
+            let mut list = counted(&token, 3);
+            list.splice(1, counted(&token, 3)).expect("1 is in range");
+            assert_eq!(Rc::strong_count(&token), 7);
+
+            drop(list);
+            assert_eq!(Rc::strong_count(&token), 1);
+        }
+    };
+}
+
+pub(crate) use leak_tests;
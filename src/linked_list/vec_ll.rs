@@ -0,0 +1,1478 @@
+use std::{cmp::Ordering, fmt, hash::{Hash, Hasher}, iter, marker::PhantomData, mem, ops::{Index, IndexMut}};
+
+use crate::ListError;
+
+/// Handle to a node of a [`VecLinkedList`]
+/// 
+/// Stays valid while other nodes are inserted or removed. Once its own node
+/// is removed the handle is stale, and lookups with it fail even after the
+/// slot has been reused for a new node. A slot is retired rather than reused
+/// once its generation runs out, so a stale handle never matches again. A
+/// handle only means something to the list that returned it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NodeId {
+    index: usize,
+    generation: u32,
+}
+
+struct Node<T> {
+    data: T,
+    next: Option<usize>,
+    prev: Option<usize>,
+}
+
+enum Entry<T> {
+    Occupied(Node<T>),
+    /// Part of the free list, holds the next free slot
+    Free(Option<usize>),
+    /// Freed with the last generation, never used again
+    Retired,
+}
+
+struct Slot<T> {
+    // Bumped every time the slot is freed, so old `NodeId`s stop matching.
+    // Wrapping around would make them match again, so the slot is retired
+    // instead of bumped past `u32::MAX`.
+    generation: u32,
+    entry: Entry<T>,
+}
+
+/// Doubly linked list that keeps its nodes in a `Vec` and links them by index
+/// 
+/// Nodes sit next to each other in memory instead of in one allocation each,
+/// and removed slots go on a free list to be reused by the next insert.
+pub struct VecLinkedList<T> {
+    slots: Vec<Slot<T>>,
+    head: Option<usize>,
+    tail: Option<usize>,
+    free: Option<usize>,
+    len: usize,
+}
+
+impl<T> Default for VecLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> VecLinkedList<T> {
+    /// Create a new list with no nodes and no storage
+    pub fn new() -> Self {
+        VecLinkedList {
+            slots: Vec::new(),
+            head: None,
+            tail: None,
+            free: None,
+            len: 0
+        }
+    }
+
+    /// Create a new list with storage for `capacity` nodes
+    pub fn with_capacity(capacity: usize) -> Self {
+        VecLinkedList {
+            slots: Vec::with_capacity(capacity),
+            ..Self::new()
+        }
+    }
+
+    /// Get the number of nodes the list can hold without reallocating
+    /// 
+    /// return:
+    /// * `usize` -> The capacity of the storage, free slots included
+    pub fn capacity(&self) -> usize {
+        self.slots.capacity()
+    }
+
+    /// Push a new node to the front of the list
+    /// 
+    /// args:
+    /// * `data`: The data to be added to the list
+    /// 
+    /// return:
+    /// * `NodeId` -> A handle to the new node
+    pub fn push_front(&mut self, data: T) -> NodeId {
+        let index = self.alloc(data, None, self.head);
+
+        match self.head {
+            Some(old_head) => self.node_mut(old_head).prev = Some(index),
+            // List is empty
+            None => self.tail = Some(index),
+        }
+
+        self.head = Some(index);
+        self.len += 1;
+        self.id(index)
+    }
+
+    /// Push a new node to the back of the list
+    /// 
+    /// args:
+    /// * `data`: The data to be added to the list
+    /// 
+    /// return:
+    /// * `NodeId` -> A handle to the new node
+    pub fn push_back(&mut self, data: T) -> NodeId {
+        let index = self.alloc(data, self.tail, None);
+
+        match self.tail {
+            Some(old_tail) => self.node_mut(old_tail).next = Some(index),
+            // List is empty
+            None => self.head = Some(index),
+        }
+
+        self.tail = Some(index);
+        self.len += 1;
+        self.id(index)
+    }
+
+    /// Insert a node/data after the first node holding a certain data.
+    /// 
+    /// args:
+    /// * `target`: The data of the node to insert after
+    /// 
+    /// * `data`: The node/data to be inserted
+    /// 
+    /// return:
+    /// * `Err(ListError::Empty)` | `Err(ListError::NotFound)`
+    pub fn push_after_node(&mut self, target: &T, data: T) -> Result<(), ListError>
+    where
+        T: PartialEq
+    {
+        if self.is_empty() {
+            return Err(ListError::Empty);
+        }
+
+        let index = self.indices()
+            .find(|&index| self.node(index).data == *target)
+            .ok_or(ListError::NotFound)?;
+        self.link_after(index, data);
+        Ok(())
+    }
+
+    /// Insert a copy of a node/data after every node holding a certain data.
+    /// 
+    /// Newly inserted nodes are never matched themselves, so this terminates
+    /// even when `data == target`.
+    /// 
+    /// args:
+    /// * `target`: The data of the node to insert after
+    /// 
+    /// * `data`: The node/data to be inserted
+    /// 
+    /// return:
+    /// * `Ok(usize)` -> The number of nodes inserted
+    /// * `Err(ListError::Empty)` | `Err(ListError::NotFound)`
+    pub fn push_after_all_nodes(&mut self, target: &T, data: T) -> Result<usize, ListError>
+    where
+        T: PartialEq + Clone
+    {
+        if self.is_empty() {
+            return Err(ListError::Empty);
+        }
+
+        let mut current = self.head;
+        let mut inserted = 0;
+
+        while let Some(index) = current {
+            if self.node(index).data == *target {
+                // Skip over the node we just inserted
+                let new_index = self.link_after(index, data.clone());
+                inserted += 1;
+                current = self.node(new_index).next;
+            } else {
+                current = self.node(index).next;
+            }
+        }
+
+        if inserted == 0 {
+            return Err(ListError::NotFound);
+        }
+        Ok(inserted)
+    }
+
+    /// Insert a node/data with that index
+    /// 
+    /// After the insert the new node is at position `index`, so `0` pushes to
+    /// the front and `len()` pushes to the back.
+    /// 
+    /// args:
+    /// * `index`: Index where the node/data will be inserted
+    /// 
+    /// * `data`: The node/data to push inserted to the `index`
+    /// 
+    /// return:
+    /// * `Ok(NodeId)` -> A handle to the new node
+    /// * `Err(ListError::IndexOutOfBounds)` -> If `index` is greater than `len()`
+    pub fn insert_at_index(&mut self, index: usize, data: T) -> Result<NodeId, ListError> {
+        if index > self.len {
+            return Err(ListError::IndexOutOfBounds { index, len: self.len });
+        }
+
+        if index == 0 {
+            return Ok(self.push_front(data));
+        }
+
+        // The node that will precede the new one
+        let prev = self.node_at(index - 1).expect("index is in range");
+        let new_index = self.link_after(prev, data);
+        Ok(self.id(new_index))
+    }
+
+    /// Remove the front node of the list
+    /// 
+    /// return:
+    /// * `Option<T>` -> The data of the removed node, `None` if the list is empty
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.head.map(|head| self.unlink(head))
+    }
+
+    /// Remove the back/end node of the list
+    /// 
+    /// return:
+    /// * `Option<T>` -> The data of the removed node, `None` if the list is empty
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.tail.map(|tail| self.unlink(tail))
+    }
+
+    /// Delete a specific node from the list
+    /// 
+    /// args:
+    /// * `data`: The node/data to be deleted if found in the list
+    /// 
+    /// return:
+    /// * `Ok(T)` -> The data of the removed node
+    /// * `Err(ListError::Empty)` | `Err(ListError::NotFound)`
+    pub fn delete_at_node(&mut self, data: &T) -> Result<T, ListError>
+    where
+        T: PartialEq
+    {
+        let (head, tail) = match (self.head, self.tail) {
+            (Some(head), Some(tail)) => (head, tail),
+            _ => return Err(ListError::Empty),
+        };
+
+        if self.node(head).data == *data {
+            return Ok(self.unlink(head));
+        }
+
+        if self.node(tail).data == *data {
+            return Ok(self.unlink(tail));
+        }
+
+        let index = self.indices()
+            .find(|&index| self.node(index).data == *data)
+            .ok_or(ListError::NotFound)?;
+        Ok(self.unlink(index))
+    }
+
+    /// Finding a node/data in a list and return the index of the node from the list
+    /// 
+    /// return:
+    /// * `(bool, usize)` -> `(true, index)` | `(false, 0)`
+    pub fn find(&self, data: &T) -> (bool, usize)
+    where
+        T: PartialEq
+    {
+        match self.iter().position(|value| value == data) {
+            Some(index) => (true, index),
+            None => (false, 0),
+        }
+    }
+
+    /// Get a reference to the data at a specific index
+    /// 
+    /// Walks from the head or the tail, whichever is closer to `index`
+    /// 
+    /// return:
+    /// * `Option<&T>` -> `None` if `index` is out of range
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.node_at(index).map(|index| &self.node(index).data)
+    }
+
+    /// Get a mutable reference to the data at a specific index
+    /// 
+    /// Walks from the head or the tail, whichever is closer to `index`
+    /// 
+    /// return:
+    /// * `Option<&mut T>` -> `None` if `index` is out of range
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let index = self.node_at(index)?;
+        Some(&mut self.node_mut(index).data)
+    }
+
+    /// Overwrite the data at a specific index
+    /// 
+    /// return:
+    /// * `Ok(T)` -> The data that was replaced
+    /// * `Err(ListError::IndexOutOfBounds)` -> If there is no node at `index`
+    pub fn set(&mut self, index: usize, data: T) -> Result<T, ListError> {
+        let len = self.len;
+        self.get_mut(index)
+            .map(|value| mem::replace(value, data))
+            .ok_or(ListError::IndexOutOfBounds { index, len })
+    }
+
+    /// Remove the node at a specific index
+    /// 
+    /// Walks from the head or the tail, whichever is closer to `index`
+    /// 
+    /// return:
+    /// * `Ok(T)` -> The data of the removed node
+    /// * `Err(ListError::IndexOutOfBounds)` -> If there is no node at `index`
+    pub fn remove_at(&mut self, index: usize) -> Result<T, ListError> {
+        let slot = self.node_at(index).ok_or(ListError::IndexOutOfBounds { index, len: self.len })?;
+        Ok(self.unlink(slot))
+    }
+
+    /// Get a handle to the head node
+    pub fn front_id(&self) -> Option<NodeId> {
+        self.head.map(|index| self.id(index))
+    }
+
+    /// Get a handle to the tail node
+    pub fn back_id(&self) -> Option<NodeId> {
+        self.tail.map(|index| self.id(index))
+    }
+
+    /// Get a handle to the node at a specific index
+    /// 
+    /// Walks from the head or the tail, whichever is closer to `index`
+    pub fn id_at(&self, index: usize) -> Option<NodeId> {
+        self.node_at(index).map(|index| self.id(index))
+    }
+
+    /// Check if a handle still points to a node of the list
+    pub fn contains_id(&self, id: NodeId) -> bool {
+        self.resolve(id).is_some()
+    }
+
+    /// Get a reference to the data of a node in O(1)
+    /// 
+    /// return:
+    /// * `Option<&T>` -> `None` if the node has been removed
+    pub fn get_by_id(&self, id: NodeId) -> Option<&T> {
+        self.resolve(id).map(|index| &self.node(index).data)
+    }
+
+    /// Get a mutable reference to the data of a node in O(1)
+    /// 
+    /// return:
+    /// * `Option<&mut T>` -> `None` if the node has been removed
+    pub fn get_by_id_mut(&mut self, id: NodeId) -> Option<&mut T> {
+        let index = self.resolve(id)?;
+        Some(&mut self.node_mut(index).data)
+    }
+
+    /// Get a handle to the node after `id`
+    /// 
+    /// return:
+    /// * `Option<NodeId>` -> `None` at the tail or if the node has been removed
+    pub fn next_id(&self, id: NodeId) -> Option<NodeId> {
+        let next = self.node(self.resolve(id)?).next?;
+        Some(self.id(next))
+    }
+
+    /// Get a handle to the node before `id`
+    /// 
+    /// return:
+    /// * `Option<NodeId>` -> `None` at the head or if the node has been removed
+    pub fn prev_id(&self, id: NodeId) -> Option<NodeId> {
+        let prev = self.node(self.resolve(id)?).prev?;
+        Some(self.id(prev))
+    }
+
+    /// Insert a new node right after the node of a handle in O(1)
+    /// 
+    /// return:
+    /// * `Ok(NodeId)` -> A handle to the new node
+    /// * `Err(ListError::NotFound)` -> If the node of `id` has been removed
+    pub fn insert_after_id(&mut self, id: NodeId, data: T) -> Result<NodeId, ListError> {
+        let index = self.resolve(id).ok_or(ListError::NotFound)?;
+        let new_index = self.link_after(index, data);
+        Ok(self.id(new_index))
+    }
+
+    /// Insert a new node right before the node of a handle in O(1)
+    /// 
+    /// return:
+    /// * `Ok(NodeId)` -> A handle to the new node
+    /// * `Err(ListError::NotFound)` -> If the node of `id` has been removed
+    pub fn insert_before_id(&mut self, id: NodeId, data: T) -> Result<NodeId, ListError> {
+        let index = self.resolve(id).ok_or(ListError::NotFound)?;
+        match self.node(index).prev {
+            Some(prev) => {
+                let new_index = self.link_after(prev, data);
+                Ok(self.id(new_index))
+            }
+            None => Ok(self.push_front(data)),
+        }
+    }
+
+    /// Remove the node of a handle in O(1)
+    /// 
+    /// return:
+    /// * `Ok(T)` -> The data of the removed node
+    /// * `Err(ListError::NotFound)` -> If the node has already been removed
+    pub fn remove_by_id(&mut self, id: NodeId) -> Result<T, ListError> {
+        let index = self.resolve(id).ok_or(ListError::NotFound)?;
+        Ok(self.unlink(index))
+    }
+
+    /// Move every node of `other` to the back of this list, leaving `other` empty
+    /// 
+    /// O(other.len()), the nodes have to move into this list's storage so
+    /// handles into `other` do not carry over
+    /// 
+    /// args:
+    /// * `other`: The list to move the nodes from
+    pub fn append(&mut self, other: &mut Self) {
+        self.extend(mem::take(other));
+    }
+
+    /// Move every node of `other` to the front of this list
+    /// 
+    /// O(other.len()), handles into this list stay valid
+    /// 
+    /// args:
+    /// * `other`: The list to put in front
+    pub fn prepend(&mut self, other: Self) {
+        for data in other.into_iter().rev() {
+            self.push_front(data);
+        }
+    }
+
+    /// Split the list in two at a specific index
+    /// 
+    /// O(len() - at), the split off nodes move into the new list's storage so
+    /// their handles do not carry over
+    /// 
+    /// args:
+    /// * `at`: The index of the first node of the returned list
+    /// 
+    /// return:
+    /// * `Ok(VecLinkedList<T>)` -> The nodes from `at` onwards
+    /// * `Err(ListError::IndexOutOfBounds)` -> If `at` is greater than `len()`
+    pub fn split_off(&mut self, at: usize) -> Result<Self, ListError> {
+        if at > self.len {
+            return Err(ListError::IndexOutOfBounds { index: at, len: self.len });
+        }
+
+        let mut other = Self::with_capacity(self.len - at);
+        while self.len > at {
+            let data = self.pop_back().expect("list is longer than at");
+            other.push_front(data);
+        }
+        Ok(other)
+    }
+
+    /// Move every node of `other` into this list at a specific index
+    /// 
+    /// After the splice the first node of `other` is at position `index`.
+    /// Handles into this list stay valid.
+    /// 
+    /// return:
+    /// * `Err(ListError::IndexOutOfBounds)` -> If `index` is greater than `len()`
+    pub fn splice_at(&mut self, index: usize, other: Self) -> Result<(), ListError> {
+        if index > self.len {
+            return Err(ListError::IndexOutOfBounds { index, len: self.len });
+        }
+
+        let mut prev = match index {
+            0 => None,
+            _ => self.node_at(index - 1),
+        };
+        for data in other {
+            let new_index = match prev {
+                Some(prev) => self.link_after(prev, data),
+                None => self.push_front(data).index,
+            };
+            prev = Some(new_index);
+        }
+        Ok(())
+    }
+
+    /// Display the list
+    pub fn display(&self)
+    where
+        T: std::fmt::Display
+    {
+        println!("{}", self);
+    }
+
+    /// Get the value of the tail node
+    /// 
+    /// return:
+    /// * `Option<&T>` -> The tail data, `None` if the list is empty
+    pub fn get_tail_value(&self) -> Option<&T> {
+        self.tail.map(|tail| &self.node(tail).data)
+    }
+
+    /// Find the index of the first node whose data matches a predicate
+    /// 
+    /// return:
+    /// * `Option<usize>` -> The index of the first match, `None` if nothing matches
+    pub fn position<F>(&self, predicate: F) -> Option<usize>
+    where
+        F: FnMut(&T) -> bool
+    {
+        self.iter().position(predicate)
+    }
+
+    /// Find the first data that matches a predicate
+    /// 
+    /// return:
+    /// * `Option<&T>` -> The first match, `None` if nothing matches
+    pub fn find_by<F>(&self, mut predicate: F) -> Option<&T>
+    where
+        F: FnMut(&T) -> bool
+    {
+        self.iter().find(|value| predicate(value))
+    }
+
+    /// Check if any node holds a specific data
+    pub fn contains(&self, data: &T) -> bool
+    where
+        T: PartialEq
+    {
+        self.iter().any(|value| value == data)
+    }
+
+    /// Keep only the nodes whose data matches a predicate, in a single pass
+    /// 
+    /// args:
+    /// * `keep`: Returns false for the nodes to remove
+    pub fn retain<F>(&mut self, mut keep: F)
+    where
+        F: FnMut(&T) -> bool
+    {
+        let mut cursor = self.cursor_front_mut();
+        while let Some(data) = cursor.current() {
+            if keep(data) {
+                cursor.move_next();
+            } else {
+                cursor.remove_current();
+            }
+        }
+    }
+
+    /// Remove the first node holding a specific data
+    /// 
+    /// return:
+    /// * `Option<T>` -> The removed data, `None` if no node holds `data`
+    pub fn remove_first(&mut self, data: &T) -> Option<T>
+    where
+        T: PartialEq
+    {
+        let index = self.indices().find(|&index| self.node(index).data == *data)?;
+        Some(self.unlink(index))
+    }
+
+    /// Remove every node holding a specific data
+    /// 
+    /// return:
+    /// * `usize` -> The number of nodes removed
+    pub fn remove_all(&mut self, data: &T) -> usize
+    where
+        T: PartialEq
+    {
+        let len = self.len;
+        self.retain(|value| value != data);
+        len - self.len
+    }
+
+    /// Get the number of nodes in the list in O(1)
+    /// 
+    /// return:
+    /// * `usize` -> The total number of items in the list
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check if the list is empty
+    /// 
+    /// return:
+    /// * `bool` -> `true` if the list has no nodes
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    /// Remove every node from the list
+    /// 
+    /// The storage is kept, every slot goes on the free list so that old
+    /// handles stay stale
+    pub fn clear(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+
+    /// Check that head/tail/prev/next links, the free list and `len` are consistent
+    /// 
+    /// Walks the whole list and panics on the first broken link. Only runs in
+    /// debug builds, in release builds it returns immediately.
+    pub fn check_links(&self) {
+        if !cfg!(debug_assertions) {
+            return;
+        }
+
+        // Every slot is either linked into the list, on the free list or retired
+        let mut free = 0;
+        let mut current = self.free;
+        while let Some(index) = current {
+            assert!(free < self.slots.len(), "free list has a cycle");
+            current = match self.slots[index].entry {
+                Entry::Free(next_free) => next_free,
+                Entry::Occupied(_) => panic!("free list reaches used slot {}", index),
+                Entry::Retired => panic!("free list reaches retired slot {}", index),
+            };
+            free += 1;
+        }
+        let retired = self.slots.iter().filter(|slot| matches!(slot.entry, Entry::Retired)).count();
+        assert_eq!(free + retired + self.len, self.slots.len(), "some slots are neither used nor free");
+
+        let (head, tail) = match (self.head, self.tail) {
+            (Some(head), Some(tail)) => (head, tail),
+            (None, None) => {
+                assert_eq!(self.len, 0, "empty list has non-zero len");
+                return;
+            }
+            _ => panic!("only one of head/tail is set"),
+        };
+
+        assert!(self.node(head).prev.is_none(), "head has a prev node");
+        assert!(self.node(tail).next.is_none(), "tail has a next node");
+
+        let mut current = head;
+        let mut counter = 1;
+        while let Some(next) = self.node(current).next {
+            assert!(counter < self.len, "list has more nodes than len");
+            assert_eq!(
+                self.node(next).prev, Some(current),
+                "node {} does not link back to node {}", counter, counter - 1
+            );
+            current = next;
+            counter += 1;
+        }
+
+        assert_eq!(current, tail, "last node is not the tail");
+        assert_eq!(counter, self.len, "len does not match the number of nodes");
+    }
+
+    /// Reverse the list by swapping the links of every node
+    pub fn reverse(&mut self) {
+        let mut current = self.head;
+        while let Some(index) = current {
+            let node = self.node_mut(index);
+            mem::swap(&mut node.next, &mut node.prev);
+            // The old next node is now behind the prev link
+            current = node.prev;
+        }
+
+        mem::swap(&mut self.head, &mut self.tail);
+    }
+
+    /// Sort the list in ascending order
+    /// 
+    /// Stable O(n log n) sort of the node indices, the nodes are relinked in
+    /// the new order and the data is never moved
+    pub fn sort(&mut self)
+    where
+        T: Ord
+    {
+        self.sort_by(T::cmp);
+    }
+
+    /// Sort the list with a comparator function, keeping equal elements in order
+    /// 
+    /// args:
+    /// * `cmp`: returns how the first element is ordered relative to the second
+    pub fn sort_by<F>(&mut self, mut cmp: F)
+    where
+        F: FnMut(&T, &T) -> Ordering
+    {
+        if self.len < 2 {
+            return;
+        }
+
+        let mut order: Vec<usize> = self.indices().collect();
+        order.sort_by(|&a, &b| cmp(&self.node(a).data, &self.node(b).data));
+
+        for (position, &index) in order.iter().enumerate() {
+            let node = self.node_mut(index);
+            node.prev = position.checked_sub(1).map(|prev| order[prev]);
+            node.next = order.get(position + 1).copied();
+        }
+        self.head = order.first().copied();
+        self.tail = order.last().copied();
+    }
+
+    /// Sort the list by the key extracted from each element, keeping equal keys in order
+    /// 
+    /// args:
+    /// * `key`: extracts the key to compare, called twice per comparison
+    pub fn sort_by_key<K, F>(&mut self, mut key: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K
+    {
+        self.sort_by(|a, b| key(a).cmp(&key(b)));
+    }
+
+    /// Insert a new node into a sorted list, after any nodes equal to it
+    /// 
+    /// args:
+    /// * `data`: the data to be stored in the new node
+    pub fn insert_sorted(&mut self, data: T)
+    where
+        T: Ord
+    {
+        let mut cursor = self.cursor_front_mut();
+        while cursor.current().is_some_and(|value| *value <= data) {
+            cursor.move_next();
+        }
+        cursor.insert_before(data);
+    }
+
+    /// Check if the list is sorted in ascending order
+    pub fn is_sorted(&self) -> bool
+    where
+        T: PartialOrd
+    {
+        self.iter().is_sorted()
+    }
+
+    /// Get a cursor pointing at the head node that can edit the list in place
+    /// 
+    /// An empty list gives a cursor at the "ghost" position, see [`CursorMut`]
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut {
+            current: self.head,
+            index: 0,
+            list: self
+        }
+    }
+
+    /// Get a cursor pointing at the tail node that can edit the list in place
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut {
+            current: self.tail,
+            index: self.len.saturating_sub(1),
+            list: self
+        }
+    }
+
+    /// Iterate over references to the data, from head to tail
+    /// 
+    /// Also iterates from tail to head through `.rev()`
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            list: self,
+            head: self.head,
+            tail: self.tail,
+            len: self.len
+        }
+    }
+
+    /// Iterate over mutable references to the data, from head to tail
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            slots: self.slots.as_mut_ptr(),
+            head: self.head,
+            tail: self.tail,
+            len: self.len,
+            _marker: PhantomData
+        }
+    }
+
+    /// Get the node in a used slot
+    fn node(&self, index: usize) -> &Node<T> {
+        match &self.slots[index].entry {
+            Entry::Occupied(node) => node,
+            Entry::Free(_) | Entry::Retired => unreachable!("link to unused slot {}", index),
+        }
+    }
+
+    /// Get the node in a used slot mutably
+    fn node_mut(&mut self, index: usize) -> &mut Node<T> {
+        match &mut self.slots[index].entry {
+            Entry::Occupied(node) => node,
+            Entry::Free(_) | Entry::Retired => unreachable!("link to unused slot {}", index),
+        }
+    }
+
+    /// Build the handle for a used slot
+    fn id(&self, index: usize) -> NodeId {
+        NodeId {
+            index,
+            generation: self.slots[index].generation
+        }
+    }
+
+    /// Get the slot of a handle, `None` if its node has been removed
+    fn resolve(&self, id: NodeId) -> Option<usize> {
+        let slot = self.slots.get(id.index)?;
+        match slot.entry {
+            Entry::Occupied(_) if slot.generation == id.generation => Some(id.index),
+            _ => None,
+        }
+    }
+
+    /// Slot indices of the nodes, from head to tail
+    fn indices(&self) -> impl Iterator<Item = usize> + '_ {
+        iter::successors(self.head, |&index| self.node(index).next)
+    }
+
+    /// Get the slot of the node at `index`, walking from whichever end is closer
+    fn node_at(&self, index: usize) -> Option<usize> {
+        if index >= self.len {
+            return None;
+        }
+
+        if index < self.len / 2 {
+            let mut current = self.head?;
+            for _ in 0..index {
+                current = self.node(current).next?;
+            }
+            Some(current)
+        } else {
+            let mut current = self.tail?;
+            for _ in index..self.len - 1 {
+                current = self.node(current).prev?;
+            }
+            Some(current)
+        }
+    }
+
+    /// Store a node in a free slot, or in a new one if there is none
+    /// 
+    /// The node is not linked in and does not count towards `len` yet
+    fn alloc(&mut self, data: T, prev: Option<usize>, next: Option<usize>) -> usize {
+        let entry = Entry::Occupied(Node { data, next, prev });
+
+        match self.free {
+            Some(index) => {
+                let slot = &mut self.slots[index];
+                self.free = match slot.entry {
+                    Entry::Free(next_free) => next_free,
+                    Entry::Occupied(_) | Entry::Retired => unreachable!("free list reaches slot {} that is not free", index),
+                };
+                slot.entry = entry;
+                index
+            }
+            None => {
+                self.slots.push(Slot { generation: 0, entry });
+                self.slots.len() - 1
+            }
+        }
+    }
+
+    /// Splice a new node in right after the node in slot `index`
+    /// 
+    /// return:
+    /// * `usize` -> The slot of the new node
+    fn link_after(&mut self, index: usize, data: T) -> usize {
+        let next = self.node(index).next;
+        let new_index = self.alloc(data, Some(index), next);
+
+        match next {
+            Some(next) => self.node_mut(next).prev = Some(new_index),
+            None => self.tail = Some(new_index),
+        }
+
+        self.node_mut(index).next = Some(new_index);
+        self.len += 1;
+        new_index
+    }
+
+    /// Unlink the node in slot `index`, put the slot on the free list (or
+    /// retire it when its generation runs out) and return the data
+    fn unlink(&mut self, index: usize) -> T {
+        let Node { prev, next, .. } = *self.node(index);
+
+        match prev {
+            Some(prev) => self.node_mut(prev).next = next,
+            None => self.head = next,
+        }
+        match next {
+            Some(next) => self.node_mut(next).prev = prev,
+            None => self.tail = prev,
+        }
+        self.len -= 1;
+
+        let slot = &mut self.slots[index];
+        let entry = match slot.generation.checked_add(1) {
+            Some(generation) => {
+                slot.generation = generation;
+                let next_free = self.free.replace(index);
+                mem::replace(&mut slot.entry, Entry::Free(next_free))
+            }
+            None => mem::replace(&mut slot.entry, Entry::Retired),
+        };
+
+        match entry {
+            Entry::Occupied(node) => node.data,
+            Entry::Free(_) | Entry::Retired => unreachable!("unlinked unused slot {}", index),
+        }
+    }
+}
+
+/// Cursor returned by [`VecLinkedList::cursor_front_mut`] and
+/// [`VecLinkedList::cursor_back_mut`]
+/// 
+/// Works like [`DoublyLinkedList`'s cursor](super::doubly_ll::CursorMut).
+/// Every operation is O(1) except for `split_after`, which has to move the
+/// split off nodes into new storage.
+pub struct CursorMut<'a, T> {
+    list: &'a mut VecLinkedList<T>,
+    current: Option<usize>,
+    index: usize,
+}
+
+impl<T> CursorMut<'_, T> {
+    /// Get the index of the node under the cursor, `None` at the ghost position
+    pub fn index(&self) -> Option<usize> {
+        self.current.map(|_| self.index)
+    }
+
+    /// Get a handle to the node under the cursor, `None` at the ghost position
+    pub fn id(&self) -> Option<NodeId> {
+        self.current.map(|current| self.list.id(current))
+    }
+
+    /// Get the data under the cursor, `None` at the ghost position
+    pub fn current(&mut self) -> Option<&mut T> {
+        let current = self.current?;
+        Some(&mut self.list.node_mut(current).data)
+    }
+
+    /// Move to the next node
+    /// 
+    /// From the tail this moves to the ghost position, and from the ghost
+    /// position to the head
+    pub fn move_next(&mut self) {
+        match self.current {
+            Some(current) => {
+                self.current = self.list.node(current).next;
+                self.index += 1;
+            }
+            None => {
+                self.current = self.list.head;
+                self.index = 0;
+            }
+        }
+    }
+
+    /// Move to the previous node
+    /// 
+    /// From the head this moves to the ghost position, and from the ghost
+    /// position to the tail
+    pub fn move_prev(&mut self) {
+        match self.current {
+            Some(current) => {
+                self.current = self.list.node(current).prev;
+                self.index = match self.current {
+                    Some(_) => self.index - 1,
+                    None => self.list.len,
+                };
+            }
+            None => {
+                self.current = self.list.tail;
+                self.index = self.list.len.saturating_sub(1);
+            }
+        }
+    }
+
+    /// Insert a new node after the cursor
+    /// 
+    /// At the ghost position the node becomes the new head
+    pub fn insert_after(&mut self, data: T) {
+        match self.current {
+            Some(current) => {
+                self.list.link_after(current, data);
+            }
+            None => {
+                self.list.push_front(data);
+                self.index += 1;
+            }
+        }
+    }
+
+    /// Insert a new node before the cursor
+    /// 
+    /// At the ghost position the node becomes the new tail
+    pub fn insert_before(&mut self, data: T) {
+        let prev = match self.current {
+            Some(current) => self.list.node(current).prev,
+            None => self.list.tail,
+        };
+
+        match prev {
+            Some(prev) => {
+                self.list.link_after(prev, data);
+            }
+            None => {
+                self.list.push_front(data);
+            }
+        }
+        self.index += 1;
+    }
+
+    /// Remove the node under the cursor and move to the next one
+    /// 
+    /// return:
+    /// * `Option<T>` -> The removed data, `None` at the ghost position
+    pub fn remove_current(&mut self) -> Option<T> {
+        let current = self.current?;
+        self.current = self.list.node(current).next;
+        Some(self.list.unlink(current))
+    }
+
+    /// Split the list in two after the cursor
+    /// 
+    /// O(len() - index) like [`VecLinkedList::split_off`], except at the ghost
+    /// position where the whole storage is handed over in O(1)
+    /// 
+    /// return:
+    /// * `VecLinkedList<T>` -> Everything after the cursor, or the whole
+    ///   list when the cursor is at the ghost position
+    pub fn split_after(&mut self) -> VecLinkedList<T> {
+        match self.current {
+            Some(_) => self.list.split_off(self.index + 1).expect("cursor is in range"),
+            None => {
+                self.index = 0;
+                mem::take(self.list)
+            }
+        }
+    }
+}
+
+/// Borrowing iterator returned by [`VecLinkedList::iter`]
+pub struct Iter<'a, T> {
+    list: &'a VecLinkedList<T>,
+    head: Option<usize>,
+    tail: Option<usize>,
+    len: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let node = self.list.node(self.head?);
+        self.head = node.next;
+        self.len -= 1;
+        Some(&node.data)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let node = self.list.node(self.tail?);
+        self.tail = node.prev;
+        self.len -= 1;
+        Some(&node.data)
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+// Written out because deriving it would require `T: Clone`
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Iter {
+            list: self.list,
+            head: self.head,
+            tail: self.tail,
+            len: self.len
+        }
+    }
+}
+
+/// Mutable iterator returned by [`VecLinkedList::iter_mut`]
+/// 
+/// Slots are visited in list order rather than storage order, so the
+/// iterator keeps a raw pointer to the storage instead of a slice iterator.
+/// It is created from `&mut VecLinkedList`, which nothing else can touch
+/// while it lives, and `len` makes sure every slot is handed out at most once.
+pub struct IterMut<'a, T> {
+    slots: *mut Slot<T>,
+    head: Option<usize>,
+    tail: Option<usize>,
+    len: usize,
+    _marker: PhantomData<&'a mut T>,
+}
+
+// SAFETY: `IterMut` only hands out `&mut T`, like `&mut VecLinkedList<T>` would
+unsafe impl<T: Send> Send for IterMut<'_, T> {}
+unsafe impl<T: Sync> Sync for IterMut<'_, T> {}
+
+impl<'a, T> IterMut<'a, T> {
+    /// Take the node in slot `index` out of the storage for the rest of `'a`
+    /// 
+    /// Safety: `index` must be a used slot that has not been handed out before
+    unsafe fn node(&mut self, index: usize) -> &'a mut Node<T> {
+        // SAFETY: guaranteed by the caller, see the comment above `IterMut`
+        match unsafe { &mut (*self.slots.add(index)).entry } {
+            Entry::Occupied(node) => node,
+            Entry::Free(_) | Entry::Retired => unreachable!("link to unused slot {}", index),
+        }
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        // SAFETY: the head is linked and `len` stops both ends at the last unseen node
+        let node = unsafe { self.node(self.head?) };
+        self.head = node.next;
+        self.len -= 1;
+        Some(&mut node.data)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        // SAFETY: see `IterMut::next`
+        let node = unsafe { self.node(self.tail?) };
+        self.tail = node.prev;
+        self.len -= 1;
+        Some(&mut node.data)
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+/// Consuming iterator returned by [`VecLinkedList::into_iter`]
+pub struct IntoIter<T>(VecLinkedList<T>);
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len, Some(self.0.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.pop_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> IntoIterator for VecLinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a VecLinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut VecLinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T> Index<usize> for VecLinkedList<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        let len = self.len;
+        self.get(index)
+            .unwrap_or_else(|| panic!("{}", ListError::IndexOutOfBounds { index, len }))
+    }
+}
+
+impl<T> IndexMut<usize> for VecLinkedList<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let len = self.len;
+        self.get_mut(index)
+            .unwrap_or_else(|| panic!("{}", ListError::IndexOutOfBounds { index, len }))
+    }
+}
+
+impl<T> Extend<T> for VecLinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.slots.reserve(iter.size_hint().0);
+        for data in iter {
+            self.push_back(data);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for VecLinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+// Cloning stores the nodes in list order and drops the free slots, so the
+// clone does not accept the original's handles
+impl<T: Clone> Clone for VecLinkedList<T> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: PartialEq> PartialEq for VecLinkedList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for VecLinkedList<T> {}
+
+impl<T: Hash> Hash for VecLinkedList<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        for data in self.iter() {
+            data.hash(state);
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for VecLinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for VecLinkedList<T> {
+    /// Renders the list from head to tail as `a -> b -> None`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for data in self.iter() {
+            write!(f, "{} -> ", data)?;
+        }
+        write!(f, "None")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::linked_list::test_utils;
+    use super::*;
+
+    /// Values from head to tail, checked against the walk from tail to head
+    fn values<T: Clone + PartialEq + fmt::Debug>(list: &VecLinkedList<T>) -> Vec<T> {
+        let values: Vec<_> = list.iter().cloned().collect();
+        let mut backwards: Vec<_> = list.iter().rev().cloned().collect();
+        backwards.reverse();
+        assert_eq!(values, backwards);
+        values
+    }
+
+    #[test]
+    fn node_id_survives_other_inserts_and_removals() {
+        let mut list = VecLinkedList::new();
+        let two = list.push_back(2);
+        let four = list.push_back(4);
+        let one = list.push_front(1);
+        let three = list.insert_after_id(two, 3).expect("two is live");
+        let zero = list.insert_before_id(one, 0).expect("one is live");
+        list.check_links();
+        assert_eq!(values(&list), [0, 1, 2, 3, 4]);
+
+        assert_eq!(list.remove_by_id(one), Ok(1));
+        assert_eq!(list.pop_back(), Some(4));
+        assert_eq!(list.insert_at_index(1, 5).map(|id| list.get_by_id(id).copied()), Ok(Some(5)));
+        list.push_front(6);
+        list.check_links();
+        assert_eq!(values(&list), [6, 0, 5, 2, 3]);
+
+        assert_eq!(list.get_by_id(zero), Some(&0));
+        assert_eq!(list.get_by_id(two), Some(&2));
+        assert_eq!(list.get_by_id(three), Some(&3));
+        assert_eq!(list.id_at(3), Some(two));
+        assert_eq!(list.next_id(two), Some(three));
+        assert_eq!(list.prev_id(zero), list.front_id());
+        assert_eq!(list.back_id(), Some(three));
+        assert!(!list.contains_id(four));
+
+        // Sorting and reversing relink the slots but leave the data in place
+        list.sort();
+        list.reverse();
+        list.check_links();
+        assert_eq!(values(&list), [6, 5, 3, 2, 0]);
+        assert_eq!(list.get_by_id(zero), Some(&0));
+        assert_eq!(list.next_id(three), Some(two));
+        if let Some(value) = list.get_by_id_mut(two) {
+            *value = 20;
+        }
+        assert_eq!(list[3], 20);
+    }
+
+    #[test]
+    fn stale_id_fails_after_its_slot_is_reused() {
+        let mut list: VecLinkedList<_> = (0..3).collect();
+        let stale = list.id_at(1).expect("index is in range");
+        assert_eq!(list.remove_by_id(stale), Ok(1));
+
+        let reused = list.push_back(7);
+        assert_eq!(reused.index, stale.index);
+        assert_ne!(reused.generation, stale.generation);
+        list.check_links();
+
+        assert_eq!(list.get_by_id(stale), None);
+        assert_eq!(list.get_by_id_mut(stale), None);
+        assert!(!list.contains_id(stale));
+        assert_eq!(list.next_id(stale), None);
+        assert_eq!(list.prev_id(stale), None);
+        assert_eq!(list.remove_by_id(stale), Err(ListError::NotFound));
+        assert_eq!(list.insert_after_id(stale, 9), Err(ListError::NotFound));
+        assert_eq!(list.insert_before_id(stale, 9), Err(ListError::NotFound));
+        assert_eq!(list.get_by_id(reused), Some(&7));
+        assert_eq!(values(&list), [0, 2, 7]);
+    }
+
+    #[test]
+    fn clear_keeps_old_ids_stale() {
+        let mut list = VecLinkedList::new();
+        let ids: Vec<_> = (0..4).map(|value| list.push_back(value)).collect();
+        list.clear();
+        list.check_links();
+        assert!(list.is_empty());
+
+        let new_ids: Vec<_> = (10..14).map(|value| list.push_back(value)).collect();
+        assert_eq!(list.slots.len(), 4);
+        assert!(ids.iter().all(|&id| !list.contains_id(id)));
+        assert!(new_ids.iter().all(|&id| list.contains_id(id)));
+    }
+
+    #[test]
+    fn free_slots_are_reused_before_the_storage_grows() {
+        let mut list: VecLinkedList<_> = (0..5).collect();
+        list.retain(|value| value % 2 == 0);
+        assert_eq!(list.remove_at(1), Ok(2));
+        list.check_links();
+        assert_eq!(list.slots.len(), 5);
+        assert_eq!(values(&list), [0, 4]);
+
+        for value in 5..8 {
+            list.push_front(value);
+            list.check_links();
+        }
+        assert_eq!(list.slots.len(), 5);
+        assert_eq!(list.free, None);
+
+        list.push_back(8);
+        list.check_links();
+        assert_eq!(list.slots.len(), 6);
+        assert_eq!(values(&list), [7, 6, 5, 0, 4, 8]);
+    }
+
+    #[test]
+    fn split_and_splice_keep_ids_of_nodes_that_stay() {
+        let mut list = VecLinkedList::new();
+        let ids: Vec<_> = (0..6).map(|value| list.push_back(value)).collect();
+        let back = list.split_off(3).expect("3 is in range");
+        list.check_links();
+        back.check_links();
+        assert_eq!(values(&back), [3, 4, 5]);
+        assert!(ids[..3].iter().all(|&id| list.contains_id(id)));
+        assert!(ids[3..].iter().all(|&id| !list.contains_id(id)));
+
+        assert!(list.splice_at(1, back).is_ok());
+        list.prepend((8..10).collect());
+        let mut cursor = list.cursor_back_mut();
+        cursor.move_prev();
+        let tail = cursor.split_after();
+        list.check_links();
+        tail.check_links();
+        assert_eq!(values(&list), [8, 9, 0, 3, 4, 5, 1]);
+        assert_eq!(values(&tail), [2]);
+        assert_eq!(list.get_by_id(ids[0]), Some(&0));
+        assert_eq!(list.get_by_id(ids[1]), Some(&1));
+        assert!(!list.contains_id(ids[2]));
+    }
+
+    #[test]
+    fn slot_is_retired_instead_of_wrapping_its_generation() {
+        let mut list: VecLinkedList<_> = (0..3).collect();
+        list.slots[1].generation = u32::MAX - 1;
+        let first = list.id_at(1).expect("index is in range");
+        assert_eq!(list.remove_by_id(first), Ok(1));
+
+        // The last generation is still handed out
+        let last = list.push_back(3);
+        assert_eq!((last.index, last.generation), (1, u32::MAX));
+        assert_eq!(list.remove_by_id(last), Ok(3));
+        list.check_links();
+        assert_eq!(list.free, None);
+
+        // Bumping again would wrap to 0, so the slot is not reused
+        let next = list.push_back(4);
+        assert_eq!(next.index, 3);
+        list.check_links();
+        for stale in [first, last] {
+            assert!(!list.contains_id(stale));
+            assert_eq!(list.remove_by_id(stale), Err(ListError::NotFound));
+        }
+        assert_eq!(values(&list), [0, 2, 4]);
+
+        list.clear();
+        list.extend(5..8);
+        list.check_links();
+        assert_eq!(list.slots.len(), 4);
+        assert_eq!(values(&list), [5, 6, 7]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "check_links only runs in debug builds")]
+    #[should_panic(expected = "neither used nor free")]
+    fn check_links_catches_a_lost_free_slot() {
+        let mut list: VecLinkedList<_> = (0..3).collect();
+        list.pop_front();
+        list.free = None;
+        list.check_links();
+    }
+
+    #[test]
+    fn std_traits() {
+        test_utils::check_std_traits::<VecLinkedList<i32>>("1 -> 2 -> 3 -> None", "None");
+    }
+}
@@ -1,7 +0,0 @@
-mod linked_list;
-
-fn main() {
-    // linked_list::singly_ll::run();
-    // linked_list::doubly_ll::run();
-    linked_list::circular_ll::run();
-}